@@ -13,5 +13,71 @@ fn row_swap_bench(c: &mut Criterion) {
     c.bench_function("row swap", |b| b.iter(|| x.swap_rows(0, 2)));
 }
 
-criterion_group!(benches, row_swap_bench);
+/// a deterministic square matrix for multiplication benchmarks
+fn sample(size: usize) -> Dense<f64> {
+    Dense::from_fn(size, size, |i, j| ((i * 31 + j * 17) % 23) as f64 - 11.)
+}
+
+fn strassen_bench(c: &mut Criterion) {
+    let a = sample(256);
+    let b = sample(256);
+
+    let mut group = c.benchmark_group("multiplication 256x256");
+    group.sample_size(10);
+    group.bench_function("naive", |bench| bench.iter(|| (&a * &b).unwrap()));
+    group.bench_function("strassen", |bench| {
+        bench.iter(|| a.strassen_mul(&b).unwrap())
+    });
+    group.finish();
+}
+
+fn blocked_bench(c: &mut Criterion) {
+    let a = sample(256);
+    let b = sample(256);
+
+    let mut group = c.benchmark_group("blocked multiplication 256x256");
+    group.sample_size(10);
+    for block in [8, 32, 64, 128] {
+        group.bench_function(format!("block {block}"), |bench| {
+            bench.iter(|| a.mul_blocked(&b, block).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn transpose_bench(c: &mut Criterion) {
+    let a = Dense::from_fn(1000, 700, |i, j| (i * 7 + j) as f64);
+
+    let mut group = c.benchmark_group("transpose 1000x700");
+    group.sample_size(10);
+    group.bench_function("naive", |bench| bench.iter(|| a.transpose()));
+    for block in [8, 16, 32, 64] {
+        group.bench_function(format!("block {block}"), |bench| {
+            bench.iter(|| a.transpose_blocked(block))
+        });
+    }
+    group.finish();
+}
+
+fn mul_vec_bench(c: &mut Criterion) {
+    let a = sample(256);
+    let v: Vec<f64> = (0..256).map(|i| i as f64 * 0.5).collect();
+    let col = Dense::col_from_vec(v.clone());
+
+    let mut group = c.benchmark_group("matrix vector 256");
+    group.bench_function("mul_vec", |bench| bench.iter(|| a.mul_vec(&v).unwrap()));
+    group.bench_function("column product", |bench| {
+        bench.iter(|| (&a * &col).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    row_swap_bench,
+    strassen_bench,
+    blocked_bench,
+    transpose_bench,
+    mul_vec_bench
+);
 criterion_main!(benches);
@@ -0,0 +1,240 @@
+//! module for the banded matrix type
+
+use crate::dense::Dense;
+use crate::matrix::{Matrix, MatrixError};
+use crate::numerics::Numeric;
+use std::ops::{Index, IndexMut, Mul};
+
+/// A struct to represent a square banded matrix of nxn
+/// Only the elements within the band are stored, all other elements are zero.
+/// The band is stored row by row where each row holds `lower + upper + 1` elements,
+/// starting from the element `lower` places to the left of the diagonal.
+/// Positions of the band which fall outside of the matrix are padded with zeros.
+#[derive(Debug, Clone)]
+pub struct Banded<T> {
+    /// represents the data of the band, N = n(lower + upper + 1)
+    pub data: Vec<T>,
+    /// the side dimensions of the matrix
+    pub n: usize,
+    pub m: usize,
+    /// the number of diagonals below the main diagonal
+    pub lower: usize,
+    /// the number of diagonals above the main diagonal
+    pub upper: usize,
+    /// returned by reference for elements outside the band
+    zero: T,
+}
+
+impl<T: Numeric> Banded<T> {
+    /// returns an n x n banded matrix with the given bandwidths filled with zeros
+    pub fn new(n: usize, lower: usize, upper: usize) -> Self {
+        Banded {
+            data: vec![T::ZERO; n * (lower + upper + 1)],
+            n,
+            m: n,
+            lower,
+            upper,
+            zero: T::ZERO,
+        }
+    }
+
+    /// creates a banded matrix from the elements within the band of a square dense matrix
+    /// elements outside of the band are ignored
+    pub fn from_dense(dense: &Dense<T>, lower: usize, upper: usize) -> Result<Self, MatrixError> {
+        if dense.m != dense.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let mut new = Banded::new(dense.n, lower, upper);
+        for i in 0..new.n {
+            for j in new.band(i) {
+                new[[i, j]] = dense[[i, j]];
+            }
+        }
+        Ok(new)
+    }
+
+    /// the range of columns within the band for row i
+    fn band(&self, i: usize) -> std::ops::Range<usize> {
+        let start = i.saturating_sub(self.lower);
+        let end = (i + self.upper + 1).min(self.n);
+        start..end
+    }
+
+    /// returns the position of an element in the data vector if it is within the band
+    fn offset(&self, idx: [usize; 2]) -> Option<usize> {
+        let [i, j] = idx;
+        if i >= self.n || j >= self.n {
+            panic!("index [{i}, {j}] out of range for a {0}x{0} matrix", self.n)
+        }
+
+        if j + self.lower < i || j > i + self.upper {
+            None
+        } else {
+            Some(i * (self.lower + self.upper + 1) + j + self.lower - i)
+        }
+    }
+}
+
+impl<T: Numeric> std::convert::From<Banded<T>> for Dense<T> {
+    fn from(mat: Banded<T>) -> Dense<T> {
+        let mut new = Dense::with_capacity(mat.n * mat.n);
+        new.m = mat.n;
+        new.n = mat.n;
+        for i in 0..mat.n {
+            for j in 0..mat.n {
+                new.data.push(mat[[i, j]])
+            }
+        }
+        new
+    }
+}
+
+impl<T: Numeric> Matrix for Banded<T> {
+    type Element = T;
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn size(&self) -> [usize; 2] {
+        [self.n, self.n]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl<T: Numeric> Index<[usize; 2]> for Banded<T> {
+    type Output = T;
+
+    /// elements outside of the band are zero
+    fn index(&self, idx: [usize; 2]) -> &T {
+        match self.offset(idx) {
+            Some(x) => &self.data[x],
+            None => &self.zero,
+        }
+    }
+}
+
+impl<T: Numeric> IndexMut<[usize; 2]> for Banded<T> {
+    /// Panics if the element is outside of the band
+    fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
+        match self.offset(idx) {
+            Some(x) => &mut self.data[x],
+            None => panic!(
+                "index [{}, {}] is outside of the band ({} lower, {} upper)",
+                idx[0], idx[1], self.lower, self.upper
+            ),
+        }
+    }
+}
+
+/// multiplication which only visits the elements within the band
+impl<T: Numeric> Mul<&Dense<T>> for &Banded<T> {
+    type Output = Result<Dense<T>, MatrixError>;
+
+    fn mul(self, other: &Dense<T>) -> Self::Output {
+        if self.n != other.m {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let mut out: Dense<T> = Dense::zeros(self.n, other.n);
+        for i in 0..self.n {
+            for k in self.band(i) {
+                let a = self[[i, k]];
+                for j in 0..other.n {
+                    out[[i, j]] += a * other[[k, j]];
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tridiagonal() -> Banded<i32> {
+        let mut a = Banded::new(4, 1, 1);
+        for i in 0..4 {
+            a[[i, i]] = 2;
+            if i > 0 {
+                a[[i, i - 1]] = -1;
+            }
+            if i < 3 {
+                a[[i, i + 1]] = -1;
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn banded_index() {
+        let a = tridiagonal();
+        assert_eq!(a.len(), 12);
+        assert_eq!(a.size(), [4, 4]);
+        assert_eq!(a[[0, 0]], 2);
+        assert_eq!(a[[2, 1]], -1);
+        assert_eq!(a[[1, 2]], -1);
+        assert_eq!(a[[0, 3]], 0);
+        assert_eq!(a[[3, 0]], 0);
+
+        let dense: Dense<i32> = a.into();
+        let expected = mat![
+            2, -1, 0, 0;
+            -1, 2, -1, 0;
+            0, -1, 2, -1;
+            0, 0, -1, 2
+        ];
+        assert_eq!(dense, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn banded_index_mut_outside_band() {
+        let mut a = tridiagonal();
+        a[[0, 2]] = 1;
+    }
+
+    #[test]
+    fn banded_from_dense() {
+        let a = mat![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        ];
+        let b = Banded::from_dense(&a, 0, 1).unwrap();
+        let dense: Dense<i32> = b.into();
+        assert_eq!(dense, mat![1, 2, 0; 0, 5, 6; 0, 0, 9]);
+
+        let c = mat![1, 2, 3];
+        assert_eq!(
+            Banded::from_dense(&c, 1, 1).unwrap_err(),
+            MatrixError::NotSquare
+        );
+    }
+
+    #[test]
+    fn banded_mul() {
+        let a = tridiagonal();
+        let v = mat![1; 2; 3; 4];
+
+        let dense: Dense<i32> = a.clone().into();
+        let expected = (&dense * &v).unwrap();
+        assert_eq!((&a * &v).unwrap(), expected);
+        assert_eq!((&a * &v).unwrap(), mat![0; 0; 0; 5]);
+
+        let b = mat![1, 0; 0, 1; 2, 3; -1, 4];
+        assert_eq!((&a * &b).unwrap(), (&dense * &b).unwrap());
+
+        let c = mat![1; 2];
+        assert_eq!((&a * &c).unwrap_err(), MatrixError::Incompatibility);
+    }
+}
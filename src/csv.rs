@@ -0,0 +1,90 @@
+//! CSV import and export for dense matrices
+
+use crate::dense::Dense;
+use crate::matrix::MatrixError;
+use std::io::{self, Read, Write};
+
+impl Dense<f64> {
+    /// reads a matrix from comma separated values, one matrix row per line
+    /// the number of columns is taken from the first row and all rows must match
+    pub fn from_csv(mut reader: impl Read) -> Result<Dense<f64>, MatrixError> {
+        let mut text = String::new();
+        reader
+            .read_to_string(&mut text)
+            .map_err(|e| MatrixError::Error(e.to_string()))?;
+
+        let mut out = Dense::new();
+        out.m = 0;
+
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let row = line
+                .split(',')
+                .map(|x| {
+                    x.trim().parse::<f64>().map_err(|e| {
+                        MatrixError::Error(format!("invalid value `{}`: {e}", x.trim()))
+                    })
+                })
+                .collect::<Result<Vec<f64>, MatrixError>>()?;
+
+            if out.m == 0 {
+                out.n = row.len();
+            } else if row.len() != out.n {
+                return Err(MatrixError::Incompatibility);
+            }
+
+            out.data.extend(row);
+            out.m += 1;
+        }
+
+        Ok(out)
+    }
+
+    /// writes the matrix as comma separated values, one matrix row per line
+    pub fn to_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        for row in self {
+            let line: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+            writeln!(writer, "{}", line.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_read() {
+        let text = "1, 2.5, -3\n4,5,6e2\n";
+        let a = Dense::from_csv(text.as_bytes()).unwrap();
+        assert_eq!(a, mat![1., 2.5, -3.; 4., 5., 600.]);
+
+        let empty = Dense::from_csv("".as_bytes()).unwrap();
+        assert!(empty.data.is_empty());
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let text = "1,2.5,-3\n4,5,600\n";
+        let a = Dense::from_csv(text.as_bytes()).unwrap();
+
+        let mut out: Vec<u8> = Vec::new();
+        a.to_csv(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), text);
+    }
+
+    #[test]
+    fn csv_err() {
+        let ragged = "1,2,3\n4,5\n";
+        assert_eq!(
+            Dense::from_csv(ragged.as_bytes()).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+
+        let invalid = "1,2\n3,x\n";
+        assert!(matches!(
+            Dense::from_csv(invalid.as_bytes()),
+            Err(MatrixError::Error(_))
+        ));
+    }
+}
@@ -0,0 +1,656 @@
+//! Matrix decompositions
+
+use crate::dense::Dense;
+use crate::matrix::{MatrixError, RowOps};
+use crate::numerics::Float;
+use crate::solver::largest_magnitude;
+use crate::symmetric::Symmetric;
+
+/// The factors of an LU decomposition: (L, U, row permutation)
+pub type LU<T> = (Dense<T>, Dense<T>, Vec<usize>);
+
+/// The factors of an LU decomposition with full pivoting: (L, U, row permutation, column permutation)
+pub type FullPivotLU<T> = (Dense<T>, Dense<T>, Vec<usize>, Vec<usize>);
+
+impl<T: Float> Dense<T> {
+    /// LU Decomposition with partial pivoting
+    /// returns the lower triangular matrix L, the upper triangular matrix U and the row
+    /// permutation such that row i of L*U is row `perm[i]` of the original matrix.
+    /// Singular matrices are decomposed with a zero on the diagonal of U
+    pub fn lu(&self) -> Result<LU<T>, MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let n = self.n;
+        let mut u = self.clone();
+        let mut l = Dense::zeros(n, n);
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            // find the largest magnitude pivot in the column at or below the diagonal
+            let mut pivot = k;
+            let mut max = u[[k, k]].abs();
+            for i in k + 1..n {
+                let check = u[[i, k]].abs();
+                if check > max {
+                    max = check;
+                    pivot = i;
+                }
+            }
+
+            if pivot != k {
+                u.swap_rows(k, pivot);
+                l.swap_rows(k, pivot);
+                perm.swap(k, pivot);
+            }
+
+            // nothing to eliminate in this column
+            if u[[k, k]] == T::ZERO {
+                continue;
+            }
+
+            for i in k + 1..n {
+                let scale = u[[i, k]] / u[[k, k]];
+                l[[i, k]] = scale;
+                u.add_rows(i, k, -scale);
+                // avoid leaving rounding residue below the diagonal
+                u[[i, k]] = T::ZERO;
+            }
+        }
+
+        for i in 0..n {
+            l[[i, i]] = T::ONE;
+        }
+
+        Ok((l, u, perm))
+    }
+
+    /// LU Decomposition with full pivoting, the largest magnitude element of the remaining
+    /// submatrix is moved to the pivot position by swapping both rows and columns.
+    /// returns L, U and the row and column permutations such that element [i, j] of L*U is
+    /// element [`rows[i]`, `cols[j]`] of the original matrix, equivalently A = P L U Qᵀ
+    pub fn lu_full_pivot(&self) -> Result<FullPivotLU<T>, MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let n = self.n;
+        let mut u = self.clone();
+        let mut l = Dense::zeros(n, n);
+        let mut rows: Vec<usize> = (0..n).collect();
+        let mut cols: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            // find the largest magnitude pivot in the submatrix at or below and right of [k, k]
+            let (mut pivot_row, mut pivot_col) = (k, k);
+            let mut max = u[[k, k]].abs();
+            for i in k..n {
+                for j in k..n {
+                    let check = u[[i, j]].abs();
+                    if check > max {
+                        max = check;
+                        pivot_row = i;
+                        pivot_col = j;
+                    }
+                }
+            }
+
+            if pivot_row != k {
+                u.swap_rows(k, pivot_row);
+                l.swap_rows(k, pivot_row);
+                rows.swap(k, pivot_row);
+            }
+            if pivot_col != k {
+                u.swap_cols(k, pivot_col);
+                cols.swap(k, pivot_col);
+            }
+
+            // the remaining submatrix is zero
+            if u[[k, k]] == T::ZERO {
+                break;
+            }
+
+            for i in k + 1..n {
+                let scale = u[[i, k]] / u[[k, k]];
+                l[[i, k]] = scale;
+                u.add_rows(i, k, -scale);
+                u[[i, k]] = T::ZERO;
+            }
+        }
+
+        for i in 0..n {
+            l[[i, i]] = T::ONE;
+        }
+
+        Ok((l, u, rows, cols))
+    }
+
+    /// Determinant calculated from the diagonal of U in the LU decomposition
+    /// singular matrices will return zero
+    pub fn det(&self) -> Result<T, MatrixError> {
+        let (_, u, perm) = self.lu()?;
+
+        // pivots lost to rounding error are treated as zero, matching inv and rref
+        let tolerance = T::EPSILON * T::from_f32(u.n as f32) * largest_magnitude(self);
+        if (0..u.n).any(|i| u[[i, i]].abs() <= tolerance) {
+            return Ok(T::ZERO);
+        }
+
+        let det = (0..u.n).fold(T::ONE, |acc, i| acc * u[[i, i]]);
+
+        if is_odd_permutation(&perm) {
+            Ok(-det)
+        } else {
+            Ok(det)
+        }
+    }
+
+    /// the determinant of the submatrix formed by removing row i and column j
+    pub fn minor(&self, i: usize, j: usize) -> Result<T, MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+        if i >= self.m || j >= self.n {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let mut sub = self.clone();
+        sub.remove_row(i);
+        sub.remove_col(j);
+        sub.det()
+    }
+
+    /// the matrix of cofactors where each element is the signed minor (-1)^(i+j) * M_ij
+    pub fn cofactor_matrix(&self) -> Result<Dense<T>, MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let mut out = Dense::zeros(self.m, self.n);
+        for i in 0..self.m {
+            for j in 0..self.n {
+                let minor = self.minor(i, j)?;
+                out[[i, j]] = if (i + j) % 2 == 0 { minor } else { -minor };
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl Dense<f64> {
+    /// QR Decomposition using the modified Gram-Schmidt process
+    /// returns Q with orthonormal columns (m x n) and the upper triangular matrix R (n x n)
+    /// only valid for matrices with at least as many rows as columns
+    pub fn qr(&self) -> Result<(Dense<f64>, Dense<f64>), MatrixError> {
+        if self.m < self.n {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let [m, n] = [self.m, self.n];
+        let mut q = self.clone();
+        let mut r = Dense::zeros(n, n);
+        // columns with a remaining norm below this are considered linearly dependent
+        let tolerance = f64::EPSILON * m as f64 * self.frobenius_norm();
+
+        for k in 0..n {
+            let norm = (0..m).map(|i| q[[i, k]] * q[[i, k]]).sum::<f64>().sqrt();
+            if norm <= tolerance {
+                return Err(MatrixError::Singularity);
+            }
+
+            r[[k, k]] = norm;
+            for i in 0..m {
+                q[[i, k]] /= norm;
+            }
+
+            // remove the component of q_k from the remaining columns
+            for j in k + 1..n {
+                let dot: f64 = (0..m).map(|i| q[[i, k]] * q[[i, j]]).sum();
+                r[[k, j]] = dot;
+                for i in 0..m {
+                    let x = dot * q[[i, k]];
+                    q[[i, j]] -= x;
+                }
+            }
+        }
+
+        Ok((q, r))
+    }
+
+    /// The dominant eigenvalue and its unit eigenvector by power iteration
+    /// The vector is repeatedly multiplied by the matrix and normalised by its Frobenius norm,
+    /// the eigenvalue is the Rayleigh quotient vᵀAv of the final vector
+    pub fn power_iteration(&self, iters: usize) -> Result<(f64, Dense<f64>), MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let mut v: Dense<f64> = Dense::ones(self.n, 1) * (1. / (self.n as f64).sqrt());
+
+        for _ in 0..iters {
+            let w = (self * &v)?;
+            let norm = w.frobenius_norm();
+            if norm == 0. {
+                // v is in the null space so its eigenvalue is zero
+                break;
+            }
+            v = w * (1. / norm);
+        }
+
+        let av = (self * &v)?;
+        let eigenvalue = (0..self.n).map(|i| v[[i, 0]] * av[[i, 0]]).sum();
+        Ok((eigenvalue, v))
+    }
+}
+
+impl Symmetric<f64> {
+    /// Cholesky decomposition of a symmetric positive definite matrix
+    /// returns the lower triangular matrix L such that L * Lᵀ = A
+    pub fn cholesky(&self) -> Result<Dense<f64>, MatrixError> {
+        let n = self.n;
+        let mut l = Dense::zeros(n, n);
+
+        for j in 0..n {
+            let sum: f64 = (0..j).map(|k| l[[j, k]] * l[[j, k]]).sum();
+            let diagonal = self[[j, j]] - sum;
+            // a NaN diagonal compares false, so it is rejected rather than accepted
+            let positive = diagonal > 0.;
+            if !positive {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            l[[j, j]] = diagonal.sqrt();
+
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[[i, k]] * l[[j, k]]).sum();
+                l[[i, j]] = (self[[i, j]] - sum) / l[[j, j]];
+            }
+        }
+
+        Ok(l)
+    }
+
+    /// Eigenvalues and eigenvectors using the cyclic Jacobi eigenvalue algorithm
+    /// Each sweep applies a rotation to zero every off-diagonal pair in turn, converging once the
+    /// norm of the off-diagonal elements is below the tolerance.
+    /// returns the eigenvalues in ascending order and the matrix whose columns are the
+    /// corresponding unit eigenvectors, or NumericInstability if max_iter sweeps do not converge
+    pub fn eigen(&self, max_iter: usize, tol: f64) -> Result<(Vec<f64>, Dense<f64>), MatrixError> {
+        let n = self.n;
+        let mut a: Dense<f64> = self.clone().into();
+        let mut v: Dense<f64> = Dense::eye(n);
+
+        let off_diagonal = |a: &Dense<f64>| {
+            a.enumerate()
+                .filter(|([i, j], _)| i != j)
+                .map(|(_, x)| x * x)
+                .sum::<f64>()
+                .sqrt()
+        };
+
+        for _ in 0..max_iter {
+            if off_diagonal(&a) < tol {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[[p, q]] == 0. {
+                        continue;
+                    }
+
+                    // the rotation angle that zeroes a[p, q], t = tan(angle)
+                    let theta = (a[[q, q]] - a[[p, p]]) / (2. * a[[p, q]]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                    let c = 1. / (t * t + 1.).sqrt();
+                    let s = t * c;
+
+                    // A = JᵀAJ applied to the columns then the rows, V = VJ
+                    for k in 0..n {
+                        let (akp, akq) = (a[[k, p]], a[[k, q]]);
+                        a[[k, p]] = c * akp - s * akq;
+                        a[[k, q]] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[[p, k]], a[[q, k]]);
+                        a[[p, k]] = c * apk - s * aqk;
+                        a[[q, k]] = s * apk + c * aqk;
+                    }
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[[k, p]], v[[k, q]]);
+                        v[[k, p]] = c * vkp - s * vkq;
+                        v[[k, q]] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        // a NaN norm compares false, so it is reported rather than accepted
+        let converged = off_diagonal(&a) < tol;
+        if !converged {
+            return Err(MatrixError::NumericInstability);
+        }
+
+        // sort the eigenvalues and reorder the eigenvector columns to match
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[[i, i]].total_cmp(&a[[j, j]]));
+
+        let values = order.iter().map(|&i| a[[i, i]]).collect();
+        let vectors = Dense::from_fn(n, n, |i, j| v[[i, order[j]]]);
+        Ok((values, vectors))
+    }
+}
+
+/// true if the permutation requires an odd number of swaps
+/// each cycle of length k in the permutation is equivalent to k - 1 swaps
+fn is_odd_permutation(perm: &[usize]) -> bool {
+    let mut visited = vec![false; perm.len()];
+    let mut swaps = 0;
+
+    for start in 0..perm.len() {
+        let mut i = start;
+        let mut len = 0;
+        while !visited[i] {
+            visited[i] = true;
+            i = perm[i];
+            len += 1;
+        }
+        if len > 0 {
+            swaps += len - 1;
+        }
+    }
+    swaps % 2 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{IntoTranspose, Matrix};
+    use crate::utilities::ApproxEq;
+
+    /// builds the permutation matrix P such that P * L * U = A
+    fn permutation(perm: &[usize]) -> Dense<f64> {
+        let n = perm.len();
+        let mut p = Dense {
+            data: vec![0.; n * n],
+            m: n,
+            n,
+        };
+        for (i, &row) in perm.iter().enumerate() {
+            p[[row, i]] = 1.;
+        }
+        p
+    }
+
+    #[test]
+    fn lu_reconstruction() {
+        let a = mat![
+            2., -1., 3.;
+            4., 1., -2.;
+            -6., 5., 1.
+        ];
+
+        let (l, u, perm) = a.lu().unwrap();
+
+        for i in 0..3 {
+            assert_eq!(l[[i, i]], 1.);
+            for j in i + 1..3 {
+                assert_eq!(l[[i, j]], 0.);
+                assert_eq!(u[[j, i]], 0.);
+            }
+        }
+
+        let plu = ((permutation(&perm) * l).unwrap() * u).unwrap();
+        plu.assert_approx_eq(&a, f64::EPSILON * 3.);
+    }
+
+    #[test]
+    fn lu_pivoting() {
+        // the zero in the first pivot position requires a row swap
+        let a = mat![
+            0., 1., 2., 1.;
+            1., 0., 3., 2.;
+            4., 2., 0., 1.;
+            1., 1., 1., 0.
+        ];
+
+        let (l, u, perm) = a.lu().unwrap();
+        assert_eq!(perm[0], 2);
+
+        let plu = ((permutation(&perm) * l).unwrap() * u).unwrap();
+        plu.assert_approx_eq(&a, f64::EPSILON * 4.);
+    }
+
+    #[test]
+    fn lu_full_pivot_reconstruction() {
+        let a = mat![
+            1e-10, 1., 2.;
+            3., -4., 1.;
+            2., 8., -5.
+        ];
+
+        let (l, u, rows, cols) = a.lu_full_pivot().unwrap();
+
+        // the largest element is moved to the first pivot
+        assert_eq!((rows[0], cols[0]), (2, 1));
+        for i in 0..3 {
+            assert_eq!(l[[i, i]], 1.);
+            for j in i + 1..3 {
+                assert_eq!(l[[i, j]], 0.);
+                assert_eq!(u[[j, i]], 0.);
+                assert!(l[[j, i]].abs() <= 1.);
+            }
+        }
+
+        let plu = ((permutation(&rows) * l).unwrap() * u).unwrap();
+        let pluqt = (plu * permutation(&cols).t()).unwrap();
+        pluqt.assert_approx_eq(&a, 1e-12);
+
+        let singular = mat![1., 2.; 2., 4.];
+        let (l, u, rows, cols) = singular.lu_full_pivot().unwrap();
+        assert_eq!(u[[1, 1]], 0.);
+        let plu = ((permutation(&rows) * l).unwrap() * u).unwrap();
+        (plu * permutation(&cols).t())
+            .unwrap()
+            .assert_approx_eq(&singular, 1e-12);
+
+        let rectangular = mat![1., 2., 3.];
+        assert_eq!(
+            rectangular.lu_full_pivot().unwrap_err(),
+            MatrixError::NotSquare
+        );
+    }
+
+    #[test]
+    fn lu_not_square() {
+        let a = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(a.lu().unwrap_err(), MatrixError::NotSquare);
+    }
+
+    #[test]
+    fn determinant() {
+        let a = mat![3., 8.; 4., 6.];
+        a.det().unwrap().assert_approx_eq(&-14., f64::EPSILON * 16.);
+
+        let b = mat![
+            6., 1., 1.;
+            4., -2., 5.;
+            2., 8., 7.
+        ];
+        b.det()
+            .unwrap()
+            .assert_approx_eq(&-306., f64::EPSILON * 512.);
+
+        // the second row is double the first
+        let c = mat![
+            1., 2., 3.;
+            2., 4., 6.;
+            1., 0., 1.
+        ];
+        assert_eq!(c.det(), Ok(0.));
+
+        // singular, but elimination leaves a pivot of rounding error
+        let e = mat![1., 2., 3.; 4., 5., 6.; 7., 8., 9.];
+        assert_eq!(e.det(), Ok(0.));
+
+        let d = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(d.det(), Err(MatrixError::NotSquare));
+    }
+
+    #[test]
+    fn permutation_parity() {
+        assert!(!is_odd_permutation(&[0, 1, 2]));
+        assert!(is_odd_permutation(&[1, 0, 2]));
+        assert!(!is_odd_permutation(&[1, 2, 0]));
+        assert!(is_odd_permutation(&[3, 2, 1, 0, 4, 6, 5]));
+    }
+
+    #[test]
+    fn minors_cofactors() {
+        let a = mat![
+            1., 2., 3.;
+            0., 4., 5.;
+            1., 0., 6.
+        ];
+
+        a.minor(0, 0).unwrap().assert_approx_eq(&24., 1e-12);
+        a.minor(1, 2).unwrap().assert_approx_eq(&-2., 1e-12);
+
+        let expected = mat![
+            24., 5., -4.;
+            -12., 3., 2.;
+            -2., -5., 4.
+        ];
+        a.cofactor_matrix()
+            .unwrap()
+            .assert_approx_eq(&expected, 1e-12);
+
+        assert_eq!(a.minor(3, 0), Err(MatrixError::Incompatibility));
+        let b = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(b.cofactor_matrix(), Err(MatrixError::NotSquare));
+        assert_eq!(b.minor(0, 0), Err(MatrixError::NotSquare));
+    }
+
+    #[test]
+    fn qr_decomposition() {
+        let a = mat![
+            12., -51., 4.;
+            6., 167., -68.;
+            -4., 24., -41.;
+            1., 2., 3.
+        ];
+
+        let (q, r) = a.qr().unwrap();
+        assert_eq!(q.size(), [4, 3]);
+        assert_eq!(r.size(), [3, 3]);
+
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(r[[i, j]], 0.);
+            }
+        }
+
+        (&q * &r).unwrap().assert_approx_eq(&a, 1e-12);
+
+        // columns of Q are orthonormal
+        let qtq = (q.t() * &q).unwrap();
+        qtq.assert_approx_eq(&Dense::eye(3), 1e-12);
+    }
+
+    #[test]
+    fn qr_err() {
+        let wide = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(wide.qr().unwrap_err(), MatrixError::Incompatibility);
+
+        let dependent = mat![1., 2.; 2., 4.; 3., 6.];
+        assert_eq!(dependent.qr().unwrap_err(), MatrixError::Singularity);
+    }
+
+    #[test]
+    fn power_iteration() {
+        // eigenvalues 5 and 2, the dominant eigenvector is (1, 1) / √2
+        let a = mat![4., 1.; 2., 3.];
+        let (value, vector) = a.power_iteration(100).unwrap();
+        value.assert_approx_eq(&5., 1e-10);
+
+        let root_half = 0.5f64.sqrt();
+        vector.assert_approx_eq(&mat![root_half; root_half], 1e-10);
+        vector.frobenius_norm().assert_approx_eq(&1., 1e-12);
+
+        let rectangular = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(
+            rectangular.power_iteration(10).unwrap_err(),
+            MatrixError::NotSquare
+        );
+    }
+
+    #[test]
+    fn cholesky() {
+        let a = symmat![
+            4.;
+            12., 37.;
+            -16., -43., 98.
+        ];
+
+        let l = a.cholesky().unwrap();
+        l.assert_approx_eq(&mat![2., 0., 0.; 6., 1., 0.; -8., 5., 3.], 1e-12);
+
+        let dense_a: Dense<f64> = a.into();
+        (&l * l.t()).unwrap().assert_approx_eq(&dense_a, 1e-12);
+
+        let indefinite = symmat![1.; 2., 1.];
+        assert_eq!(
+            indefinite.cholesky().unwrap_err(),
+            MatrixError::NotPositiveDefinite
+        );
+
+        let nan = symmat![f64::NAN; 1., 2.];
+        assert_eq!(
+            nan.cholesky().unwrap_err(),
+            MatrixError::NotPositiveDefinite
+        );
+        let nan = symmat![1.; f64::NAN, 2.];
+        assert_eq!(
+            nan.cholesky().unwrap_err(),
+            MatrixError::NotPositiveDefinite
+        );
+    }
+
+    #[test]
+    fn jacobi_eigen() {
+        let a = symmat![
+            2.;
+            1., 2.;
+            0., 1., 2.
+        ];
+
+        let (values, vectors) = a.eigen(50, 1e-12).unwrap();
+        let root2 = 2f64.sqrt();
+        values.assert_approx_eq(&vec![2. - root2, 2., 2. + root2], 1e-10);
+
+        // A v = λ v for each column and the eigenvectors are orthonormal
+        let dense_a: Dense<f64> = a.into();
+        let av = (&dense_a * &vectors).unwrap();
+        let vl = Dense::from_fn(3, 3, |i, j| vectors[[i, j]] * values[j]);
+        av.assert_approx_eq(&vl, 1e-10);
+        (vectors.t() * &vectors)
+            .unwrap()
+            .assert_approx_eq(&Dense::eye(3), 1e-10);
+
+        let diagonal = symmat![3.; 0., -1.];
+        let (values, _) = diagonal.eigen(1, 1e-12).unwrap();
+        assert_eq!(values, vec![-1., 3.]);
+
+        let b = symmat![4.; 1., 3.; 2., 0.5, 1.];
+        assert_eq!(
+            b.eigen(1, 1e-14).unwrap_err(),
+            MatrixError::NumericInstability
+        );
+
+        let nan = symmat![1.; f64::NAN, 2.];
+        assert_eq!(
+            nan.eigen(10, 1e-12).unwrap_err(),
+            MatrixError::NumericInstability
+        );
+    }
+}
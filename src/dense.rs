@@ -1,9 +1,11 @@
 //! module for the dense matrix type
 
 use crate::matrix::{Concatenate, IntoTranspose, IntoTransposeMut, Matrix, MatrixError, RowOps};
-use crate::numerics::Numeric;
+use crate::numerics::{Float, Numeric};
 use crate::utilities::ApproxEq;
-use std::ops::{Index, IndexMut, Mul, MulAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Range, Sub, SubAssign,
+};
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -46,7 +48,8 @@ use std::fmt::{Debug, Display, Formatter};
 /// assert_eq!(a[0][1], -9);
 /// # }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Dense<T: Numeric> {
     /// a vector containing the Matrix data
     pub data: Vec<T>,
@@ -76,6 +79,35 @@ impl<T: Numeric> Matrix for Dense<T> {
     }
 }
 
+/// Deserialization checks that the length of the data matches the dimensions
+#[cfg(feature = "serde")]
+impl<'de, T: Numeric + serde::Deserialize<'de>> serde::Deserialize<'de> for Dense<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Dense")]
+        struct Raw<T> {
+            data: Vec<T>,
+            m: usize,
+            n: usize,
+        }
+
+        let Raw { data, m, n } = Raw::deserialize(deserializer)?;
+        let len = m.checked_mul(n).ok_or_else(|| {
+            serde::de::Error::custom(format!("matrix dimensions {m}x{n} overflow"))
+        })?;
+        if data.len() != len {
+            return Err(serde::de::Error::custom(format!(
+                "expected {} elements for a {}x{} matrix, found {}",
+                len,
+                m,
+                n,
+                data.len()
+            )));
+        }
+        Ok(Dense { data, m, n })
+    }
+}
+
 impl<T: Numeric> Default for Dense<T> {
     fn default() -> Self {
         Dense {
@@ -86,6 +118,13 @@ impl<T: Numeric> Default for Dense<T> {
     }
 }
 
+/// matrices are only equal if both the dimensions and the data are equal
+impl<T: Numeric> PartialEq for Dense<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.m == other.m && self.n == other.n && self.data == other.data
+    }
+}
+
 impl<T: Numeric> Index<[usize; 2]> for Dense<T> {
     type Output = T;
     /// takes i, j returns the element
@@ -110,6 +149,31 @@ impl<T: Numeric> IndexMut<[usize; 2]> for Dense<T> {
     }
 }
 
+/// indexing by a range of rows returns the contiguous slice of those rows in row-major order
+/// Index must return a reference so an owned matrix of the rows is available from submatrix
+impl<T: Numeric> Index<Range<usize>> for Dense<T> {
+    type Output = [T];
+
+    fn index(&self, rows: Range<usize>) -> &Self::Output {
+        &self.data[rows.start * self.n..rows.end * self.n]
+    }
+}
+
+/// tuple indexing, equivalent to indexing by [i, j]
+impl<T: Numeric> Index<(usize, usize)> for Dense<T> {
+    type Output = T;
+
+    fn index(&self, idx: (usize, usize)) -> &T {
+        &self[[idx.0, idx.1]]
+    }
+}
+
+impl<T: Numeric> IndexMut<(usize, usize)> for Dense<T> {
+    fn index_mut(&mut self, idx: (usize, usize)) -> &mut T {
+        &mut self[[idx.0, idx.1]]
+    }
+}
+
 impl<T: Numeric> IndexMut<usize> for Dense<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         let a = self.n * index;
@@ -122,19 +186,26 @@ impl<T: Numeric> Display for Dense<T> {
         // closure to format each element
         let precision = f.precision().unwrap_or(2);
         let format = |x: &T| format!("{x:.precision$}");
-        // first run through to find the max length of each formatted element
-        // elements are stored in a vec as we go
-        let mut strings: Vec<String> = vec![];
-        let max: usize = self.data.iter().fold(0, |max: usize, x: &T| {
-            let s = format(x);
-            let disp_len = s.len();
-            strings.push(s);
-            if max > disp_len {
-                max
-            } else {
-                disp_len
+        // format each element first so the max length can be found
+        let mut strings: Vec<String> = self.data.iter().map(format).collect();
+        // the alternate flag aligns on the decimal point
+        if f.alternate() {
+            strings = align_decimals(strings);
+        }
+        // a width given to the formatter overrides the computed column width
+        let max: usize = match f.width() {
+            Some(width) => width,
+            None => {
+                strings.iter().fold(0, |max: usize, s| {
+                    let disp_len = s.len();
+                    if max > disp_len {
+                        max
+                    } else {
+                        disp_len
+                    }
+                }) + 2
             }
-        }) + 2;
+        };
 
         // iterate through the stored vector folding each formatted element into a final string
         // also adding a new line when each element divides evenly into the number of rows
@@ -152,6 +223,43 @@ impl<T: Numeric> Display for Dense<T> {
     }
 }
 
+/// shows the dimensions followed by the grid as laid out by Display
+impl<T: Numeric> std::fmt::Debug for Dense<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Dense {{ m: {}, n: {} }}", self.m, self.n)?;
+        if !self.data.is_empty() {
+            write!(f, "\n{self}")?;
+        }
+        Ok(())
+    }
+}
+
+/// the absolute difference between two values without requiring a signed type
+fn difference<T: Numeric>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// pads formatted numbers so that the decimal points line up, keeping each sign next to its digits
+fn align_decimals(strings: Vec<String>) -> Vec<String> {
+    // split each string into its signed integer and fractional parts
+    let parts: Vec<(&str, &str)> = strings
+        .iter()
+        .map(|s| s.split_at(s.find('.').unwrap_or(s.len())))
+        .collect();
+
+    let int_width = parts.iter().map(|p| p.0.len()).max().unwrap_or(0);
+    let frac_width = parts.iter().map(|p| p.1.len()).max().unwrap_or(0);
+
+    parts
+        .iter()
+        .map(|(int, frac)| format!("{int:>int_width$}{frac:<frac_width$}"))
+        .collect()
+}
+
 impl<T: Numeric> Dense<T> {
     /// returns an empty matrix
     pub fn new() -> Self {
@@ -177,32 +285,504 @@ impl<T: Numeric> Dense<T> {
         }
     }
 
+    /// creates an m x n matrix from row-major data, the length of the data must be m * n
+    pub fn from_vec(data: Vec<T>, m: usize, n: usize) -> Result<Self, MatrixError> {
+        if m.checked_mul(n) != Some(data.len()) {
+            return Err(MatrixError::Incompatibility);
+        }
+        Ok(Dense { data, m, n })
+    }
+
     /// Very efficient way to transpose a single dimension matrix
     pub fn swap_mn(&mut self) {
         unsafe { std::ptr::swap(&mut self.m, &mut self.n) }
     }
 
+    /// returns an m x n matrix filled with zeros
+    pub fn zeros(m: usize, n: usize) -> Dense<T> {
+        Dense {
+            data: vec![T::ZERO; m * n],
+            m,
+            n,
+        }
+    }
+
+    /// returns an m x n matrix filled with ones
+    pub fn ones(m: usize, n: usize) -> Dense<T> {
+        Dense {
+            data: vec![T::ONE; m * n],
+            m,
+            n,
+        }
+    }
+
+    /// returns a square matrix with the values on the main diagonal and zeros elsewhere
+    pub fn from_diagonal(v: &[T]) -> Dense<T> {
+        let mut out = Dense::zeros(v.len(), v.len());
+        for (i, &x) in v.iter().enumerate() {
+            out[[i, i]] = x;
+        }
+        out
+    }
+
     /// returns an identity matrix
     /// Will require a type
     pub fn eye(size: usize) -> Dense<T>
     where
         T: std::convert::From<u32>,
     {
-        let mut v: Vec<T> = Vec::new();
-        for i in 0..size {
-            for j in 0..size {
-                if i == j {
-                    v.push(1.into());
-                } else {
-                    v.push(0.into());
+        Dense::from_fn(size, size, |i, j| if i == j { 1.into() } else { 0.into() })
+    }
+
+    /// the outer product, an a.len() x b.len() matrix where [i, j] = a[i] * b[j]
+    pub fn outer(a: &[T], b: &[T]) -> Dense<T> {
+        Dense::from_fn(a.len(), b.len(), |i, j| a[i] * b[j])
+    }
+
+    /// creates an m x n matrix where each element is the result of f(i, j)
+    pub fn from_fn<F: Fn(usize, usize) -> T>(m: usize, n: usize, f: F) -> Dense<T> {
+        let mut data: Vec<T> = Vec::with_capacity(m * n);
+        for i in 0..m {
+            for j in 0..n {
+                data.push(f(i, j));
+            }
+        }
+        Dense { data, m, n }
+    }
+
+    /// returns a new matrix which is the transpose of this matrix
+    /// unlike `t()` the data is physically rearranged into a new row-major vector
+    pub fn transpose(&self) -> Dense<T> {
+        let mut new: Dense<T> = Dense::with_capacity(self.data.len());
+        new.m = self.n;
+        new.n = self.m;
+
+        for j in 0..self.n {
+            for i in 0..self.m {
+                new.data.push(self[[i, j]]);
+            }
+        }
+        new
+    }
+
+    /// returns a transposed copy, copying in block x block tiles so that both the reads
+    /// and the writes stay within cache for large matrices
+    ///
+    /// # Panics
+    /// if the block size is zero
+    pub fn transpose_blocked(&self, block: usize) -> Dense<T> {
+        assert!(block > 0, "block size must be greater than zero");
+        let mut new: Dense<T> = Dense::zeros(self.n, self.m);
+
+        for ii in (0..self.m).step_by(block) {
+            let i_end = (ii + block).min(self.m);
+            for jj in (0..self.n).step_by(block) {
+                let j_end = (jj + block).min(self.n);
+
+                for i in ii..i_end {
+                    for j in jj..j_end {
+                        new[[j, i]] = self[[i, j]];
+                    }
                 }
             }
         }
-        Dense::<T> {
-            data: v,
-            m: size,
-            n: size,
+        new
+    }
+
+    /// swaps columns a and b
+    /// Panics if either column is out of bounds
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.n && b < self.n,
+            "column out of bounds: {a}, {b} for {} columns",
+            self.n
+        );
+        for i in 0..self.m {
+            self.swap([i, a], [i, b]);
+        }
+    }
+
+    /// transposes a square matrix in place by swapping each element above the diagonal
+    /// with its mirror below it
+    pub fn transpose_square_inplace(&mut self) -> Result<(), MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+        for i in 0..self.n {
+            for j in (i + 1)..self.n {
+                self.swap([i, j], [j, i]);
+            }
+        }
+        Ok(())
+    }
+
+    /// returns a reference to the element at i, j or None if the index is out of bounds
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i >= self.m || j >= self.n {
+            None
+        } else {
+            self.data.get(j + i * self.n)
+        }
+    }
+
+    /// returns a mutable reference to the element at i, j or None if the index is out of bounds
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i >= self.m || j >= self.n {
+            None
+        } else {
+            self.data.get_mut(j + i * self.n)
+        }
+    }
+
+    /// changes the dimensions to m x n keeping each element at its [i, j] position,
+    /// rows and columns are truncated when shrinking and padded with the fill value when growing
+    pub fn resize(&mut self, m: usize, n: usize, fill: T) {
+        let old = std::mem::take(&mut self.data);
+        let old_n = self.n;
+        let (keep_m, keep_n) = (self.m.min(m), old_n.min(n));
+
+        let mut data = Vec::with_capacity(m * n);
+        for i in 0..m {
+            if i < keep_m {
+                data.extend_from_slice(&old[i * old_n..i * old_n + keep_n]);
+            }
+            data.resize((i + 1) * n, fill);
+        }
+
+        self.data = data;
+        self.m = m;
+        self.n = n;
+    }
+
+    /// reinterprets the data with new dimensions, the number of elements must not change
+    pub fn reshape(self, m: usize, n: usize) -> Result<Dense<T>, MatrixError> {
+        if m * n != self.data.len() {
+            return Err(MatrixError::Incompatibility);
+        }
+        Ok(Dense { m, n, ..self })
+    }
+
+    /// copies the block of rows and columns within the given ranges into a new matrix
+    pub fn submatrix(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<Dense<T>, MatrixError> {
+        if rows.start > rows.end || cols.start > cols.end || rows.end > self.m || cols.end > self.n
+        {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let mut new: Dense<T> = Dense::with_capacity(rows.len() * cols.len());
+        new.m = rows.len();
+        new.n = cols.len();
+
+        for i in rows {
+            new.data.extend_from_slice(&self[i][cols.clone()]);
+        }
+        Ok(new)
+    }
+
+    /// removes row i from the matrix
+    pub fn remove_row(&mut self, i: usize) {
+        assert!(i < self.m, "row {} out of range for {} rows", i, self.m);
+        let a = self.n * i;
+        self.data.drain(a..a + self.n);
+        self.m -= 1;
+    }
+
+    /// removes column j from the matrix
+    pub fn remove_col(&mut self, j: usize) {
+        assert!(
+            j < self.n,
+            "column {} out of range for {} columns",
+            j,
+            self.n
+        );
+        let n = self.n;
+        let mut k = 0;
+        self.data.retain(|_| {
+            let keep = k % n != j;
+            k += 1;
+            keep
+        });
+        self.n -= 1;
+    }
+
+    /// inserts a row before row i, the row must have a length of n
+    pub fn insert_row(&mut self, i: usize, row: &[T]) -> Result<(), MatrixError> {
+        if row.len() != self.n || i > self.m {
+            return Err(MatrixError::Incompatibility);
+        }
+        let a = self.n * i;
+        self.data.splice(a..a, row.iter().copied());
+        self.m += 1;
+        Ok(())
+    }
+
+    /// inserts a column before column j, the column must have a length of m
+    pub fn insert_col(&mut self, j: usize, col: &[T]) -> Result<(), MatrixError> {
+        if col.len() != self.m || j > self.n {
+            return Err(MatrixError::Incompatibility);
+        }
+        let mut data = Vec::with_capacity(self.data.len() + col.len());
+        for (i, &x) in col.iter().enumerate() {
+            data.extend_from_slice(&self[i][..j]);
+            data.push(x);
+            data.extend_from_slice(&self[i][j..]);
+        }
+        self.data = data;
+        self.n += 1;
+        Ok(())
+    }
+
+    /// returns the rows as owned nested vectors
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.into_iter().map(|row| row.to_vec()).collect()
+    }
+
+    /// formats the matrix as a LaTeX bmatrix environment, floats are written with the given
+    /// number of decimal places
+    pub fn to_latex(&self, precision: usize) -> String {
+        let rows: Vec<String> = self
+            .into_iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| format!("{x:.precision$}"))
+                    .collect::<Vec<String>>()
+                    .join(" & ")
+            })
+            .collect();
+
+        format!(
+            "\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}",
+            rows.join(" \\\\\n")
+        )
+    }
+
+    /// sets every element to value
+    pub fn fill(&mut self, value: T) {
+        self.data.iter_mut().for_each(|x| *x = value);
+    }
+
+    /// sets the main diagonal to value, for rectangular matrices this is the first min(m, n)
+    pub fn fill_diagonal(&mut self, value: T) {
+        let len = if self.m < self.n { self.m } else { self.n };
+        for i in 0..len {
+            self[[i, i]] = value;
+        }
+    }
+
+    /// adds a row of length n to every row of the matrix
+    pub fn add_row_broadcast(&self, row: &[T]) -> Result<Dense<T>, MatrixError> {
+        if row.len() != self.n {
+            return Err(MatrixError::Incompatibility);
+        }
+        Ok(Dense::from_fn(self.m, self.n, |i, j| self[[i, j]] + row[j]))
+    }
+
+    /// adds a column of length m to every column of the matrix
+    pub fn add_col_broadcast(&self, col: &[T]) -> Result<Dense<T>, MatrixError> {
+        if col.len() != self.m {
+            return Err(MatrixError::Incompatibility);
+        }
+        Ok(Dense::from_fn(self.m, self.n, |i, j| self[[i, j]] + col[i]))
+    }
+
+    /// returns a matrix of the same shape with 1 where the predicate holds and 0 elsewhere
+    pub fn mask<F: Fn(&T) -> bool>(&self, pred: F) -> Dense<u8> {
+        self.map(|x| if pred(&x) { 1 } else { 0 })
+    }
+
+    /// true if the matrix is square and each element is within the tolerance of its mirror
+    /// across the diagonal, integer types can use a tolerance of zero
+    pub fn is_symmetric(&self, tolerance: T) -> bool {
+        if self.m != self.n {
+            return false;
+        }
+        (0..self.n)
+            .all(|i| (i + 1..self.n).all(|j| difference(self[[i, j]], self[[j, i]]) <= tolerance))
+    }
+
+    /// true if every element off the main diagonal is within the tolerance of zero
+    pub fn is_diagonal(&self, tol: T) -> bool {
+        self.enumerate()
+            .all(|([i, j], &x)| i == j || difference(x, T::ZERO) <= tol)
+    }
+
+    /// true if every element below the main diagonal is within the tolerance of zero
+    pub fn is_upper_triangular(&self, tol: T) -> bool {
+        self.enumerate()
+            .all(|([i, j], &x)| i <= j || difference(x, T::ZERO) <= tol)
+    }
+
+    /// true if every element above the main diagonal is within the tolerance of zero
+    pub fn is_lower_triangular(&self, tol: T) -> bool {
+        self.enumerate()
+            .all(|([i, j], &x)| i >= j || difference(x, T::ZERO) <= tol)
+    }
+
+    /// the number of non-zero elements
+    pub fn nnz(&self) -> usize {
+        self.data.iter().filter(|&&x| x != T::ZERO).count()
+    }
+
+    /// the fraction of elements that are non-zero, NaN for an empty matrix
+    pub fn density(&self) -> f64 {
+        self.nnz() as f64 / self.data.len() as f64
+    }
+
+    /// returns the main diagonal, for rectangular matrices this has a length of min(m, n)
+    pub fn diagonal(&self) -> Vec<T> {
+        let len = if self.m < self.n { self.m } else { self.n };
+        (0..len).map(|i| self[[i, i]]).collect()
+    }
+
+    /// returns the sum of all elements
+    pub fn sum(&self) -> T {
+        self.data.iter().copied().sum()
+    }
+
+    /// returns the product of all elements
+    pub fn product(&self) -> T {
+        self.data.iter().fold(T::ONE, |acc, &x| acc * x)
+    }
+
+    /// returns the largest element or None if the matrix is empty
+    pub fn max(&self) -> Option<T> {
+        self.data
+            .iter()
+            .copied()
+            .reduce(|max, x| if x > max { x } else { max })
+    }
+
+    /// returns the smallest element or None if the matrix is empty
+    pub fn min(&self) -> Option<T> {
+        self.data
+            .iter()
+            .copied()
+            .reduce(|min, x| if x < min { x } else { min })
+    }
+
+    /// returns the [i, j] of the largest element or None if the matrix is empty
+    /// the first occurrence is returned for ties
+    pub fn argmax(&self) -> Option<[usize; 2]> {
+        self.enumerate()
+            .reduce(|max, x| if x.1 > max.1 { x } else { max })
+            .map(|(idx, _)| idx)
+    }
+
+    /// returns the [i, j] of the smallest element or None if the matrix is empty
+    /// the first occurrence is returned for ties
+    pub fn argmin(&self) -> Option<[usize; 2]> {
+        self.enumerate()
+            .reduce(|min, x| if x.1 < min.1 { x } else { min })
+            .map(|(idx, _)| idx)
+    }
+
+    /// returns the sum of each row
+    pub fn row_sums(&self) -> Vec<T> {
+        self.into_iter()
+            .map(|row| row.iter().copied().sum())
+            .collect()
+    }
+
+    /// returns the sum of each column
+    pub fn col_sums(&self) -> Vec<T> {
+        let mut sums = vec![T::ZERO; self.n];
+        for row in self {
+            sums.iter_mut().zip(row).for_each(|(s, &x)| *s += x);
+        }
+        sums
+    }
+
+    /// returns the sum of the main diagonal of a square matrix
+    pub fn trace(&self) -> Result<T, MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+        Ok((0..self.n).map(|i| self[[i, i]]).sum())
+    }
+
+    /// returns a new matrix with each element multiplied by the factor, unlike Mul<T> the
+    /// original matrix is borrowed rather than consumed
+    pub fn scaled(&self, factor: T) -> Dense<T> {
+        self.map(|x| x * factor)
+    }
+
+    /// returns a new matrix of the same shape with the function applied to each element
+    pub fn map<U: Numeric, F: Fn(T) -> U>(&self, f: F) -> Dense<U> {
+        Dense {
+            data: self.data.iter().map(|&x| f(x)).collect(),
+            m: self.m,
+            n: self.n,
+        }
+    }
+
+    /// combines two matrices of the same dimensions by applying the function element-wise
+    pub fn zip_map<F: Fn(T, T) -> T>(
+        &self,
+        other: &Dense<T>,
+        f: F,
+    ) -> Result<Dense<T>, MatrixError> {
+        if self.m != other.m || self.n != other.n {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(&x, &y)| f(x, y))
+            .collect();
+
+        Ok(Dense { data, ..*self })
+    }
+
+    /// raises a square matrix to an integer power using exponentiation by squaring
+    /// the zeroth power is the identity matrix
+    pub fn pow(&self, exp: u32) -> Result<Dense<T>, MatrixError>
+    where
+        for<'a> &'a Dense<T>: Mul<&'a Dense<T>, Output = Result<Dense<T>, MatrixError>>,
+    {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let mut out = Dense::from_fn(self.n, self.n, |i, j| if i == j { T::ONE } else { T::ZERO });
+        let mut base = self.clone();
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp % 2 == 1 {
+                out = (&out * &base)?;
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = (&base * &base)?;
+            }
         }
+        Ok(out)
+    }
+
+    /// Kronecker product where each element of self scales a copy of other
+    /// the result is a (m1 * m2) x (n1 * n2) matrix
+    pub fn kron(&self, other: &Dense<T>) -> Dense<T> {
+        let m = self.m * other.m;
+        let n = self.n * other.n;
+        Dense::from_fn(m, n, |i, j| {
+            self[[i / other.m, j / other.n]] * other[[i % other.m, j % other.n]]
+        })
+    }
+
+    /// element-wise (Hadamard) product of two matrices of the same dimensions
+    pub fn hadamard(&self, other: &Dense<T>) -> Result<Dense<T>, MatrixError> {
+        self.zip_map(other, |x, y| x * y)
+    }
+
+    /// returns the sum of the squares of all elements
+    pub fn norm_sq(&self) -> T {
+        self.data.iter().map(|&x| x * x).sum()
     }
 
     /// swaps two elements in the vector
@@ -216,6 +796,33 @@ impl<T: Numeric> Dense<T> {
         }
     }
 
+    /// returns an iterator over each element and its [i, j] coordinate in row-major order
+    pub fn enumerate(&self) -> impl Iterator<Item = ([usize; 2], &T)> {
+        let n = self.n;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(k, x)| ([k / n, k % n], x))
+    }
+
+    /// returns an iterator over the columns of the matrix
+    pub fn col_iter(&self) -> DenseColIterator<'_, T> {
+        DenseColIterator { matrix: self, j: 0 }
+    }
+
+    /// stacks two matrices vertically such that the rows of other follow the rows of self
+    pub fn vconcatenate(mut self, other: Dense<T>) -> Result<Dense<T>, MatrixError> {
+        match self.n == other.n {
+            true => {
+                // row-major storage means the data can simply be appended
+                self.data.extend(other.data);
+                self.m += other.m;
+                Ok(self)
+            }
+            false => Err(MatrixError::Incompatibility),
+        }
+    }
+
     pub fn concatenate_vec(self, other: &[T]) -> Result<Dense<T>, MatrixError> {
         match self.m == other.len() {
             true => {
@@ -237,6 +844,82 @@ impl<T: Numeric> Dense<T> {
     }
 }
 
+impl Dense<f64> {
+    /// the Frobenius norm, the square root of the sum of the squares of all elements
+    pub fn frobenius_norm(&self) -> f64 {
+        self.norm_sq().sqrt()
+    }
+
+    /// the mean of all elements, NaN for an empty matrix
+    pub fn mean(&self) -> f64 {
+        if self.data.is_empty() {
+            return f64::NAN;
+        }
+        self.welford().0
+    }
+
+    /// the population variance of all elements, NaN for an empty matrix
+    pub fn variance(&self) -> f64 {
+        if self.data.is_empty() {
+            return f64::NAN;
+        }
+        self.welford().1 / self.data.len() as f64
+    }
+
+    /// divides each row by its Euclidean norm, rows of zeros are left unchanged
+    pub fn normalize_rows(&self) -> Dense<f64> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for row in self {
+            let norm = row.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0. {
+                data.extend_from_slice(row);
+            } else {
+                data.extend(row.iter().map(|x| x / norm));
+            }
+        }
+        Dense {
+            data,
+            m: self.m,
+            n: self.n,
+        }
+    }
+
+    /// subtracts each column's mean and divides by its population standard deviation,
+    /// columns with zero variance are left unchanged
+    pub fn standardize_columns(&self) -> Dense<f64> {
+        let m = self.m as f64;
+        let means: Vec<f64> = self.col_sums().iter().map(|s| s / m).collect();
+        let deviations = Dense::from_fn(self.m, self.n, |i, j| self[[i, j]] - means[j]);
+        let std_devs: Vec<f64> = deviations
+            .map(|x| x * x)
+            .col_sums()
+            .iter()
+            .map(|s| (s / m).sqrt())
+            .collect();
+
+        Dense::from_fn(self.m, self.n, |i, j| {
+            if std_devs[j] == 0. {
+                self[[i, j]]
+            } else {
+                deviations[[i, j]] / std_devs[j]
+            }
+        })
+    }
+
+    /// Welford's single pass algorithm, returns the mean and the sum of squared differences
+    /// from the mean. Updating a running mean avoids the cancellation of summing squares.
+    fn welford(&self) -> (f64, f64) {
+        self.data
+            .iter()
+            .enumerate()
+            .fold((0., 0.), |(mean, m2), (k, &x)| {
+                let delta = x - mean;
+                let mean = mean + delta / (k + 1) as f64;
+                (mean, m2 + delta * (x - mean))
+            })
+    }
+}
+
 impl<T: Numeric> std::convert::From<Vec<T>> for Dense<T> {
     fn from(data: Vec<T>) -> Self {
         let n = data.len();
@@ -255,6 +938,40 @@ impl<T: Numeric> std::convert::From<&[T]> for Dense<T> {
     }
 }
 
+/// flattens nested row vectors, ragged rows are incompatible
+impl<T: Numeric> std::convert::TryFrom<Vec<Vec<T>>> for Dense<T> {
+    type Error = MatrixError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let m = rows.len();
+        let n = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != n) {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        Ok(Dense {
+            data: rows.into_iter().flatten().collect(),
+            m,
+            n,
+        })
+    }
+}
+
+/// collects into a column vector, matching the `mat![0; 1; 2]` syntax
+impl<T: Numeric> std::iter::FromIterator<T> for Dense<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Dense::col_from_vec(iter.into_iter().collect())
+    }
+}
+
+/// the inner product of two equal length slices, such as the rows of a matrix
+pub fn dot<T: Numeric>(a: &[T], b: &[T]) -> Result<T, MatrixError> {
+    if a.len() != b.len() {
+        return Err(MatrixError::Incompatibility);
+    }
+    Ok(a.iter().zip(b).map(|(&x, &y)| x * y).sum())
+}
+
 pub trait IntoCol<T: Numeric> {
     fn into_col(self) -> Dense<T>;
 }
@@ -355,8 +1072,31 @@ impl<'a, T: Numeric> Iterator for DenseIntoIterator<'a, T> {
     }
 }
 
-/// multiplying a Matrix by a scalar of the same type
-impl<T: Numeric> Mul<T> for Dense<T> {
+/// Iterates over the columns of a dense matrix
+/// As the data is stored row-major each column is gathered into a new vector
+pub struct DenseColIterator<'a, T: Numeric> {
+    matrix: &'a Dense<T>,
+    j: usize,
+}
+
+impl<'a, T: Numeric> Iterator for DenseColIterator<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.j < self.matrix.n {
+            let out = (0..self.matrix.m)
+                .map(|i| self.matrix[[i, self.j]])
+                .collect();
+            self.j += 1;
+            Some(out)
+        } else {
+            None
+        }
+    }
+}
+
+/// multiplying a Matrix by a scalar of the same type
+impl<T: Numeric> Mul<T> for Dense<T> {
     type Output = Self;
 
     fn mul(self, scalar: T) -> Self {
@@ -372,6 +1112,102 @@ impl<T: Numeric> MulAssign<T> for Dense<T> {
     }
 }
 
+/// negates every element, only available for signed element types
+impl<T: Numeric + Neg<Output = T>> Neg for Dense<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let v: Vec<T> = self.data.into_iter().map(|x| -x).collect();
+
+        Dense { data: v, ..self }
+    }
+}
+
+/// dividing a Matrix by a scalar of the same type
+impl<T: Numeric> Div<T> for Dense<T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        let v: Vec<T> = self.data.into_iter().map(|x| x / scalar).collect();
+
+        Dense { data: v, ..self }
+    }
+}
+
+impl<T: Numeric> DivAssign<T> for Dense<T> {
+    fn div_assign(&mut self, scalar: T) {
+        self.data.iter_mut().for_each(|x| *x /= scalar)
+    }
+}
+
+/// element-wise addition of two matrices of the same dimensions
+impl<T: Numeric> Add<Dense<T>> for Dense<T> {
+    type Output = Result<Dense<T>, MatrixError>;
+
+    fn add(mut self, other: Dense<T>) -> Self::Output {
+        if self.m != other.m || self.n != other.n {
+            return Err(MatrixError::Incompatibility);
+        }
+        self.data
+            .iter_mut()
+            .zip(other.data)
+            .for_each(|(x, y)| *x += y);
+        Ok(self)
+    }
+}
+
+/// element-wise subtraction of two matrices of the same dimensions
+impl<T: Numeric> Sub<Dense<T>> for Dense<T> {
+    type Output = Result<Dense<T>, MatrixError>;
+
+    fn sub(mut self, other: Dense<T>) -> Self::Output {
+        if self.m != other.m || self.n != other.n {
+            return Err(MatrixError::Incompatibility);
+        }
+        self.data
+            .iter_mut()
+            .zip(other.data)
+            .for_each(|(x, y)| *x -= y);
+        Ok(self)
+    }
+}
+
+/// Panics if the dimensions of the matrices differ
+impl<T: Numeric> AddAssign<Dense<T>> for Dense<T> {
+    fn add_assign(&mut self, other: Dense<T>) {
+        assert!(
+            self.m == other.m && self.n == other.n,
+            "matrix dimensions differ: {}x{} += {}x{}",
+            self.m,
+            self.n,
+            other.m,
+            other.n
+        );
+        self.data
+            .iter_mut()
+            .zip(other.data)
+            .for_each(|(x, y)| *x += y)
+    }
+}
+
+/// Panics if the dimensions of the matrices differ
+impl<T: Numeric> SubAssign<Dense<T>> for Dense<T> {
+    fn sub_assign(&mut self, other: Dense<T>) {
+        assert!(
+            self.m == other.m && self.n == other.n,
+            "matrix dimensions differ: {}x{} -= {}x{}",
+            self.m,
+            self.n,
+            other.m,
+            other.n
+        );
+        self.data
+            .iter_mut()
+            .zip(other.data)
+            .for_each(|(x, y)| *x -= y)
+    }
+}
+
 impl<'a, T: Numeric> From<Dense<T>> for Cow<'a, Dense<T>> {
     fn from(m: Dense<T>) -> Self {
         Cow::Owned(m)
@@ -384,10 +1220,11 @@ impl<'a, T: Numeric> From<&'a Dense<T>> for Cow<'a, Dense<T>> {
     }
 }
 
-impl ApproxEq<Dense<f64>> for Dense<f64> {
-    type Check = f64;
+/// generic over the float precision, the tolerance is of the element type
+impl<T: Float> ApproxEq<Dense<T>> for Dense<T> {
+    type Check = T;
 
-    fn approx_eq(&self, other: &Dense<f64>, tolerance: Self::Check) -> bool {
+    fn approx_eq(&self, other: &Dense<T>, tolerance: Self::Check) -> bool {
         if self.m != other.m || self.n != other.n {
             return false;
         }
@@ -402,7 +1239,7 @@ impl ApproxEq<Dense<f64>> for Dense<f64> {
         true
     }
 
-    fn assert_approx_eq(&self, other: &Dense<f64>, tolerance: Self::Check) {
+    fn assert_approx_eq(&self, other: &Dense<T>, tolerance: Self::Check) {
         if self.m != other.m || self.n != other.n {
             panic!(
                 r#"assertion failed: Dimension Inequality
@@ -457,7 +1294,7 @@ impl<'a, T: Numeric + 'a> Display for DenseTranspose<'a, T> {
         // skip the previous starting values followed by a step by iterator to achieve the
         // correct order
         let mut strings: Vec<String> = vec![];
-        let max: usize = (0..self.m).into_iter().fold(0, |max, i| {
+        let max: usize = (0..self.m).fold(0, |max, i| {
             self.inner
                 .data
                 .iter()
@@ -499,7 +1336,7 @@ impl<'a, T: Numeric + 'a> Display for DenseTransposeMut<'a, T> {
         // skip the previous starting values followed by a step by iterator to achieve the
         // correct order
         let mut strings: Vec<String> = vec![];
-        let max: usize = (0..self.m).into_iter().fold(0, |max, i| {
+        let max: usize = (0..self.m).fold(0, |max, i| {
             self.inner
                 .data
                 .iter()
@@ -517,145 +1354,743 @@ impl<'a, T: Numeric + 'a> Display for DenseTransposeMut<'a, T> {
                 })
         }) + 2;
 
-        // exactly the same folding operation as the dense Display implementation
-        let string = strings
-            .iter()
-            .enumerate()
-            .fold("".to_string(), |mut s, (i, x)| {
-                if i % self.n == 0 && i != 0 {
-                    s.push('\n')
-                }
-                format!("{}{:>width$}", s, x, width = max)
-            });
+        // exactly the same folding operation as the dense Display implementation
+        let string = strings
+            .iter()
+            .enumerate()
+            .fold("".to_string(), |mut s, (i, x)| {
+                if i % self.n == 0 && i != 0 {
+                    s.push('\n')
+                }
+                format!("{}{:>width$}", s, x, width = max)
+            });
+
+        write!(f, "{}", string)
+    }
+}
+
+impl<'a, T: Numeric + 'a> IntoTranspose<'a> for Dense<T> {
+    type TransposeView = DenseTranspose<'a, T>;
+
+    fn t(&'a self) -> Self::TransposeView {
+        DenseTranspose {
+            inner: self,
+            m: self.n,
+            n: self.m,
+        }
+    }
+}
+
+impl<'a, T: Numeric + 'a> IntoTransposeMut<'a> for Dense<T> {
+    type TransposeViewMut = DenseTransposeMut<'a, T>;
+
+    fn t_mut(&'a mut self) -> Self::TransposeViewMut {
+        let m = self.n;
+        let n = self.m;
+
+        DenseTransposeMut { inner: self, m, n }
+    }
+}
+
+impl<T: Numeric> Index<[usize; 2]> for DenseTranspose<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        &self.inner[[index[1], index[0]]]
+    }
+}
+
+impl<T: Numeric> Index<[usize; 2]> for DenseTransposeMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        &self.inner[[index[1], index[0]]]
+    }
+}
+
+impl<T: Numeric> IndexMut<[usize; 2]> for DenseTransposeMut<'_, T> {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        &mut self.inner[[index[1], index[0]]]
+    }
+}
+
+impl<'a, T: Numeric + 'a> Matrix for DenseTranspose<'a, T> {
+    type Element = T;
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn size(&self) -> [usize; 2] {
+        self.inner.size()
+    }
+
+    fn into_vec(self) -> Vec<Self::Element> {
+        (0..self.m)
+            .flat_map(|i| {
+                self.inner
+                    .data
+                    .iter()
+                    .skip(i)
+                    .step_by(self.m)
+                    .copied()
+                    .collect::<Vec<Self::Element>>()
+            })
+            .collect()
+    }
+}
+
+/// iterates over the rows of the transpose, which are the columns of the original matrix
+impl<'a, T: Numeric> IntoIterator for &DenseTranspose<'a, T> {
+    type Item = Vec<T>;
+    type IntoIter = DenseColIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DenseColIterator {
+            matrix: self.inner,
+            j: 0,
+        }
+    }
+}
+
+impl<'a, T: Numeric + 'a> Matrix for DenseTransposeMut<'a, T> {
+    type Element = T;
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn size(&self) -> [usize; 2] {
+        self.inner.size()
+    }
+
+    fn into_vec(self) -> Vec<Self::Element> {
+        (0..self.m)
+            .flat_map(|i| {
+                self.inner
+                    .data
+                    .iter()
+                    .skip(i)
+                    .step_by(self.m)
+                    .copied()
+                    .collect::<Vec<Self::Element>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose() {
+        let mut a = mat![1, 2, 3; 4, 5, 6];
+        assert_eq!(a[[0, 1]], 2);
+
+        let b = a.t();
+        assert_eq!(b[[0, 1]], 4);
+
+        a.t_mut()[[2, 0]] = 7;
+        assert_eq!(a[[0, 2]], 7);
+    }
+
+    #[test]
+    fn test_transpose_alloc() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        let b = a.transpose();
+        assert_eq!(b, mat![1, 4; 2, 5; 3, 6]);
+        assert_eq!(b.transpose(), a);
+
+        let c = mat![1, 2, 3, 4];
+        assert_eq!(c.transpose(), mat![1; 2; 3; 4]);
+
+        let e: Dense<i32> = Dense::new();
+        let et = e.transpose();
+        assert!(et.is_empty());
+        assert_eq!(et.size(), [0, 1]);
+    }
+
+    #[test]
+    fn checked_access() {
+        let mut a = mat![0, 1, 2; 3, 4, 5];
+        assert_eq!(a.get(1, 2), Some(&5));
+        assert_eq!(a.get(0, 0), Some(&0));
+        // the flat index would be valid for these but the coordinates are not
+        assert_eq!(a.get(2, 0), None);
+        assert_eq!(a.get(0, 3), None);
+
+        *a.get_mut(1, 0).unwrap() = 9;
+        assert_eq!(a[[1, 0]], 9);
+        assert!(a.get_mut(2, 1).is_none());
+        assert!(a.get_mut(1, 3).is_none());
+    }
+
+    #[test]
+    fn norms() {
+        let a = mat![3., 4.];
+        assert_eq!(a.frobenius_norm(), 5.);
+
+        let b = mat![1., -2.; 2., -4.];
+        assert_eq!(b.frobenius_norm(), 5.);
+
+        let c = mat![1, 2; 3, 4];
+        assert_eq!(c.norm_sq(), 30);
+    }
+
+    #[test]
+    fn mean_variance() {
+        let a = mat![2., 4., 4., 4.; 5., 5., 7., 9.];
+        assert_eq!(a.mean(), 5.);
+        assert_eq!(a.variance(), 4.);
+
+        // a naive sum of squares loses all precision at this offset
+        let b = a.map(|x| x + 1e8);
+        assert!((b.mean() - (1e8 + 5.)).abs() < 1e-6);
+        assert!((b.variance() - 4.).abs() < 1e-6);
+
+        let empty: Dense<f64> = Dense::new();
+        assert!(empty.mean().is_nan());
+        assert!(empty.variance().is_nan());
+    }
+
+    #[test]
+    fn normalize_rows() {
+        let a = mat![3., 4.; 0., 0.; -1., 1.];
+        let b = a.normalize_rows();
+        assert_eq!(b.size(), [3, 2]);
+
+        for i in [0, 2] {
+            dot(&b[i], &b[i]).unwrap().assert_approx_eq(&1., 1e-12);
+        }
+        assert_eq!(b[0], [0.6, 0.8]);
+        assert_eq!(b[1], [0., 0.]);
+    }
+
+    #[test]
+    fn standardize_columns() {
+        let a = mat![
+            1., 10., 3.;
+            2., 20., 3.;
+            3., 60., 3.;
+            6., 30., 3.
+        ];
+        let z = a.standardize_columns();
+        assert_eq!(z.size(), [4, 3]);
+
+        for j in 0..2 {
+            let col = Dense::col_from_vec((0..4).map(|i| z[[i, j]]).collect());
+            assert!(col.mean().abs() < 1e-12);
+            assert!((col.variance() - 1.).abs() < 1e-12);
+        }
+
+        // the constant column is left as it was
+        for i in 0..4 {
+            assert_eq!(z[[i, 2]], 3.);
+        }
+    }
+
+    #[test]
+    fn map() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        assert_eq!(a.map(|x| x * 2), mat![2, 4, 6; 8, 10, 12]);
+
+        let b: Dense<f64> = a.map(|x| x as f64 / 2.);
+        assert_eq!(b.size(), [2, 3]);
+        b.assert_approx_eq(&mat![0.5, 1., 1.5; 2., 2.5, 3.], f64::EPSILON);
+    }
+
+    #[test]
+    fn reshape() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        assert_eq!(a.clone().reshape(3, 2).unwrap(), mat![1, 2; 3, 4; 5, 6]);
+        assert_eq!(a.clone().reshape(1, 6).unwrap(), mat![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a.reshape(2, 2).unwrap_err(), MatrixError::Incompatibility);
+    }
+
+    #[test]
+    fn submatrix() {
+        let a = mat![
+            1, 2, 3, 4;
+            5, 6, 7, 8;
+            9, 10, 11, 12;
+            13, 14, 15, 16
+        ];
+        assert_eq!(a.submatrix(1..3, 1..3).unwrap(), mat![6, 7; 10, 11]);
+        assert_eq!(a.submatrix(0..1, 0..4).unwrap(), mat![1, 2, 3, 4]);
+        assert_eq!(a.submatrix(2..4, 3..4).unwrap(), mat![12; 16]);
+
+        assert_eq!(
+            a.submatrix(2..5, 0..2).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+        assert_eq!(
+            a.submatrix(0..2, 3..5).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+    }
+
+    #[test]
+    fn remove_rows_cols() {
+        let mut a = mat![1, 2, 3; 4, 5, 6; 7, 8, 9];
+        a.remove_row(1);
+        assert_eq!(a, mat![1, 2, 3; 7, 8, 9]);
+
+        a.remove_col(0);
+        assert_eq!(a, mat![2, 3; 8, 9]);
+
+        a.remove_col(1);
+        assert_eq!(a, mat![2; 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_row_out_of_range() {
+        let mut a = mat![1, 2; 3, 4];
+        a.remove_row(2);
+    }
+
+    #[test]
+    fn insert_rows_cols() {
+        let mut a = mat![1, 2; 3, 4];
+        a.insert_row(0, &[5, 6]).unwrap();
+        assert_eq!(a, mat![5, 6; 1, 2; 3, 4]);
+        a.insert_row(2, &[7, 8]).unwrap();
+        assert_eq!(a, mat![5, 6; 1, 2; 7, 8; 3, 4]);
+        a.insert_row(4, &[9, 0]).unwrap();
+        assert_eq!(a, mat![5, 6; 1, 2; 7, 8; 3, 4; 9, 0]);
+
+        let mut b = mat![1, 2; 3, 4];
+        b.insert_col(1, &[7, 8]).unwrap();
+        assert_eq!(b, mat![1, 7, 2; 3, 8, 4]);
+        b.insert_col(3, &[0, 0]).unwrap();
+        assert_eq!(b, mat![1, 7, 2, 0; 3, 8, 4, 0]);
+
+        assert_eq!(
+            b.insert_row(1, &[1, 2]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+        assert_eq!(
+            b.insert_col(0, &[1, 2, 3]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+        assert_eq!(
+            b.insert_col(5, &[1, 2]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+    }
+
+    #[test]
+    fn diagonals() {
+        let a = mat![1, 2, 3; 4, 5, 6; 7, 8, 9];
+        assert_eq!(a.diagonal(), vec![1, 5, 9]);
+        assert_eq!(mat![1, 2, 3; 4, 5, 6].diagonal(), vec![1, 5]);
+        assert_eq!(mat![1; 2; 3].diagonal(), vec![1]);
+
+        let d = Dense::from_diagonal(&[1, 5, 9]);
+        assert_eq!(d, mat![1, 0, 0; 0, 5, 0; 0, 0, 9]);
+        assert_eq!(d.diagonal(), a.diagonal());
+
+        let eye: Dense<u32> = Dense::eye(3);
+        assert_eq!(Dense::from_diagonal(&[1, 1, 1]), eye);
+    }
+
+    #[test]
+    fn reductions() {
+        let a = mat![1, -2, 3; 4, 5, -6];
+        assert_eq!(a.sum(), 5);
+        assert_eq!(a.product(), 720);
+        assert_eq!(a.max(), Some(5));
+        assert_eq!(a.min(), Some(-6));
+
+        let b = mat![0.5, 2.; -1.5, 4.];
+        assert_eq!(b.sum(), 5.);
+        assert_eq!(b.product(), -6.);
+
+        let empty: Dense<i32> = Dense::new();
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.min(), None);
+    }
+
+    #[test]
+    fn row_col_sums() {
+        let a = mat![1, 2, 3; 10, 20, 30];
+        assert_eq!(a.row_sums(), vec![6, 60]);
+        assert_eq!(a.col_sums(), vec![11, 22, 33]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let a = mat![1, 2, 3; 4, 5, 6; 7, 8, 9];
+        assert_eq!(a.trace(), Ok(15));
+
+        let b = mat![1, 2, 3; 4, 5, 6];
+        assert_eq!(b.trace(), Err(MatrixError::NotSquare));
+    }
+
+    #[test]
+    fn test_raw_matrix() {
+        let a = Dense {
+            data: vec![0, 1, 2, 3, 4, 5],
+            n: 3,
+            m: 2,
+        };
+        assert_eq!(a[[0, 0]], 0);
+        assert_eq!(a[[0, 1]], 1);
+        assert_eq!(a[[0, 2]], 2);
+        assert_eq!(a[[1, 0]], 3);
+        assert_eq!(a[[1, 1]], 4);
+        assert_eq!(a[[1, 2]], 5);
+
+        let i: Dense<u32> = Dense::eye(3);
+        let eye3 = mat![
+            1, 0, 0;
+            0, 1, 0;
+            0, 0, 1
+        ];
+
+        assert_eq!(i, eye3);
+
+        let j: Dense<f64> = Dense::eye(2);
+        j.assert_approx_eq(&mat![1., 0.; 0., 1.], f64::EPSILON)
+    }
+
+    #[test]
+    fn from_fn() {
+        let a: Dense<u32> = Dense::from_fn(3, 3, |i, j| if i == j { 1 } else { 0 });
+        assert_eq!(a, Dense::eye(3));
+
+        let b = Dense::from_fn(2, 3, |i, j| 10 * i + j);
+        assert_eq!(b, mat![0, 1, 2; 10, 11, 12]);
+    }
+
+    #[test]
+    fn zeros_ones() {
+        let z: Dense<i32> = Dense::zeros(2, 3);
+        assert_eq!(z.size(), [2, 3]);
+        assert_eq!(z, mat![0, 0, 0; 0, 0, 0]);
+
+        let o: Dense<f64> = Dense::ones(3, 1);
+        assert_eq!(o.size(), [3, 1]);
+        assert!(o.data.iter().all(|&x| x == 1.));
+    }
+
+    #[test]
+    fn try_from_nested() {
+        use std::convert::TryFrom;
+
+        let a = Dense::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(a, mat![1, 2, 3; 4, 5, 6]);
+
+        let ragged = Dense::try_from(vec![vec![1., 2.], vec![3.]]);
+        assert_eq!(ragged.unwrap_err(), MatrixError::Incompatibility);
+
+        let empty: Dense<i32> = Dense::try_from(Vec::<Vec<i32>>::new()).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.size(), [0, 0]);
+    }
+
+    #[test]
+    fn to_rows() {
+        use std::convert::TryFrom;
+
+        let a = mat![1, 2, 3; 4, 5, 6];
+        let rows = a.to_rows();
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(Dense::try_from(rows).unwrap(), a);
+    }
+
+    #[test]
+    fn outer() {
+        assert_eq!(Dense::outer(&[1, 2, 3], &[4, 5]), mat![4, 5; 8, 10; 12, 15]);
+
+        let empty = Dense::outer(&[1., 2.], &[]);
+        assert_eq!(empty.size(), [2, 0]);
+    }
+
+    #[test]
+    fn dot_product() {
+        assert_eq!(dot(&[1, 2, 3], &[4, 5, 6]).unwrap(), 32);
+        assert_eq!(
+            dot(&[1., 2.], &[3.]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+
+        let a = mat![1, 2; 3, 4];
+        assert_eq!(dot(&a[0], &a[1]).unwrap(), 11);
+        assert_eq!(dot::<i32>(&[], &[]).unwrap(), 0);
+    }
+
+    #[test]
+    fn fill() {
+        let mut a: Dense<f64> = Dense::zeros(2, 3);
+        a.fill(1.5);
+        assert_eq!(a, mat![1.5 => 2, 3]);
+
+        a.fill_diagonal(-2.);
+        assert_eq!(a, mat![-2., 1.5, 1.5; 1.5, -2., 1.5]);
+
+        let mut b = mat![1, 2; 3, 4; 5, 6];
+        b.fill_diagonal(0);
+        assert_eq!(b, mat![0, 2; 3, 0; 5, 6]);
+    }
 
-        write!(f, "{}", string)
+    #[test]
+    fn broadcast() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+
+        let rows = a.add_row_broadcast(&[10, 20, 30]).unwrap();
+        assert_eq!(rows, (a.clone() + mat![10, 20, 30; 10, 20, 30]).unwrap());
+
+        let cols = a.add_col_broadcast(&[-1, 1]).unwrap();
+        assert_eq!(cols, (a.clone() + mat![-1, -1, -1; 1, 1, 1]).unwrap());
+
+        assert_eq!(
+            a.add_row_broadcast(&[1, 2]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+        assert_eq!(
+            a.add_col_broadcast(&[1, 2, 3]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
     }
-}
 
-impl<'a, T: Numeric + 'a> IntoTranspose<'a> for Dense<T> {
-    type TransposeView = DenseTranspose<'a, T>;
+    #[test]
+    fn mask() {
+        let a = mat![-1, 2, 0; 4, -5, 6];
+        let positive = a.mask(|&x| x > 0);
+        assert_eq!(positive, mat![0, 1, 0; 1, 0, 1]);
 
-    fn t(&'a self) -> Self::TransposeView {
-        DenseTranspose {
-            inner: self,
-            m: self.n,
-            n: self.m,
-        }
+        // zero the negative entries
+        let relu = a.hadamard(&positive.map(|x| x as i32)).unwrap();
+        assert_eq!(relu, mat![0, 2, 0; 4, 0, 6]);
     }
-}
 
-impl<'a, T: Numeric + 'a> IntoTransposeMut<'a> for Dense<T> {
-    type TransposeViewMut = DenseTransposeMut<'a, T>;
+    #[test]
+    fn argmax_argmin() {
+        let a = mat![3., -1., 4.; 1., 5., -9.; 2., 6., 5.];
+        assert_eq!(a.argmax(), Some([2, 1]));
+        assert_eq!(a.argmin(), Some([1, 2]));
 
-    fn t_mut(&'a mut self) -> Self::TransposeViewMut {
-        let m = self.n;
-        let n = self.m;
+        let ties = mat![7, 1; 7, 1];
+        assert_eq!(ties.argmax(), Some([0, 0]));
+        assert_eq!(ties.argmin(), Some([0, 1]));
 
-        DenseTransposeMut { inner: self, m, n }
+        let empty: Dense<i32> = Dense::new();
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.argmin(), None);
     }
-}
 
-impl<T: Numeric> Index<[usize; 2]> for DenseTranspose<'_, T> {
-    type Output = T;
+    #[test]
+    fn row_range_index() {
+        let a = Dense::from_fn(4, 3, |i, j| (i * 3 + j) as i32);
+        assert_eq!(a[1..3], [3, 4, 5, 6, 7, 8]);
+        assert_eq!(a[1..3], a.submatrix(1..3, 0..3).unwrap().data[..]);
+        assert_eq!(a[0..1], a[0]);
+        assert!(a[2..2].is_empty());
+    }
 
-    fn index(&self, index: [usize; 2]) -> &Self::Output {
-        &self.inner[[index[1], index[0]]]
+    #[test]
+    fn tuple_index() {
+        let mut a = mat![1, 2, 3; 4, 5, 6];
+        assert_eq!(a[(0, 2)], 3);
+        assert_eq!(a[(1, 0)], a[[1, 0]]);
+
+        a[(1, 1)] = 50;
+        assert_eq!(a[[1, 1]], 50);
+        assert_eq!(a, mat![1, 2, 3; 4, 50, 6]);
     }
-}
 
-impl<T: Numeric> Index<[usize; 2]> for DenseTransposeMut<'_, T> {
-    type Output = T;
+    #[test]
+    fn transpose_blocked() {
+        let a = Dense::from_fn(100, 37, |i, j| (i * 37 + j) as f64 * 0.5);
+        let expected = a.transpose();
+        for block in [1, 8, 16, 37, 64, 128] {
+            assert_eq!(a.transpose_blocked(block), expected);
+        }
 
-    fn index(&self, index: [usize; 2]) -> &Self::Output {
-        &self.inner[[index[1], index[0]]]
+        let empty: Dense<i32> = Dense::zeros(0, 3);
+        assert_eq!(empty.transpose_blocked(4).size(), [3, 0]);
     }
-}
 
-impl<T: Numeric> IndexMut<[usize; 2]> for DenseTransposeMut<'_, T> {
-    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
-        &mut self.inner[[index[1], index[0]]]
+    #[test]
+    fn swap_cols() {
+        let mut a = mat![1, 2, 3; 4, 5, 6; 7, 8, 9; 10, 11, 12];
+        a.swap_cols(0, 2);
+        assert_eq!(a, mat![3, 2, 1; 6, 5, 4; 9, 8, 7; 12, 11, 10]);
+
+        a.swap_cols(1, 1);
+        assert_eq!(a, mat![3, 2, 1; 6, 5, 4; 9, 8, 7; 12, 11, 10]);
     }
-}
 
-impl<'a, T: Numeric + 'a> Matrix for DenseTranspose<'a, T> {
-    type Element = T;
+    #[test]
+    #[should_panic]
+    fn swap_cols_out_of_bounds() {
+        let mut a = mat![1, 2; 3, 4];
+        a.swap_cols(0, 2);
+    }
 
-    fn len(&self) -> usize {
-        self.inner.len()
+    #[test]
+    fn is_symmetric() {
+        let a = mat![1, 2, 3; 2, 5, 6; 3, 6, 9];
+        assert!(a.is_symmetric(0));
+
+        let b = mat![1., 2.; 2. + 1e-12, 3.];
+        assert!(b.is_symmetric(1e-10));
+        assert!(!b.is_symmetric(0.));
+
+        let c: Dense<u32> = mat![1, 7; 2, 1];
+        assert!(!c.is_symmetric(0));
+        assert!(c.is_symmetric(5));
+
+        assert!(!mat![1, 2, 3; 2, 1, 4].is_symmetric(0));
     }
 
-    fn size(&self) -> [usize; 2] {
-        self.inner.size()
+    #[test]
+    fn structure_predicates() {
+        let diagonal = mat![2., 0., 0.; 0., -1., 1e-14; 0., 0., 3.];
+        assert!(diagonal.is_diagonal(1e-12));
+        assert!(!diagonal.is_diagonal(0.));
+        assert!(diagonal.is_upper_triangular(0.));
+        assert!(diagonal.is_lower_triangular(1e-12));
+
+        let upper = mat![1, 2, 3; 0, 4, 5; 0, 0, 6];
+        assert!(upper.is_upper_triangular(0));
+        assert!(!upper.is_lower_triangular(0));
+        assert!(!upper.is_diagonal(0));
+
+        let lower = upper.transpose();
+        assert!(lower.is_lower_triangular(0));
+        assert!(!lower.is_upper_triangular(0));
+
+        // rectangular matrices are checked against their main diagonal
+        let wide = mat![1, 2, 3; 0, 4, 5];
+        assert!(wide.is_upper_triangular(0));
+        assert!(!wide.is_lower_triangular(0));
     }
 
-    fn into_vec(self) -> Vec<Self::Element> {
-        (0..self.m)
-            .into_iter().flat_map(|i| {
-                self.inner.data.iter().skip(i).step_by(self.m).copied().collect::<Vec<Self::Element>>()
-            }).collect()
+    #[test]
+    fn scaled() {
+        let a = mat![1, -2; 3, 4];
+        let b = a.scaled(3);
+        assert_eq!(b, mat![3, -6; 9, 12]);
+        assert_eq!(a, mat![1, -2; 3, 4]);
+        assert_eq!(b, a * 3);
     }
-}
 
-impl<'a, T: Numeric + 'a> Matrix for DenseTransposeMut<'a, T> {
-    type Element = T;
+    #[test]
+    fn nnz_density() {
+        let a = mat![1, 0, 0, 2; 0, 0, 3, 0];
+        assert_eq!(a.nnz(), 3);
+        assert_eq!(a.density(), 0.375);
 
-    fn len(&self) -> usize {
-        self.inner.len()
+        let b: Dense<f64> = Dense::zeros(3, 3);
+        assert_eq!(b.nnz(), 0);
+        assert_eq!(b.density(), 0.);
+
+        let empty: Dense<f64> = Dense::new();
+        assert!(empty.density().is_nan());
     }
 
-    fn size(&self) -> [usize; 2] {
-        self.inner.size()
+    #[test]
+    fn mat_macro_trailing_separators() {
+        let expected = mat![1, 2; 3, 4];
+        assert_eq!(mat![1, 2,; 3, 4,;], expected);
+        assert_eq!(mat![1, 2; 3, 4;], expected);
+        assert_eq!(mat![1, 2,; 3, 4], expected);
+        assert_eq!(mat![1, 2, 3,], mat![1, 2, 3]);
+        assert_eq!(mat![1; 2; 3;], Dense::col_from_vec(vec![1, 2, 3]));
     }
 
-    fn into_vec(self) -> Vec<Self::Element> {
-        (0..self.m)
-            .into_iter().flat_map(|i| {
-                self.inner.data.iter().skip(i).step_by(self.m).copied().collect::<Vec<Self::Element>>()
-            }).collect()
+    #[test]
+    fn mat_macro_range() {
+        let a = mat![range 0..6 => 2, 3];
+        assert_eq!(a, mat![0, 1, 2; 3, 4, 5]);
+        assert_eq!(a[[1, 0]], 3);
+
+        let b: Dense<i64> = mat![range 1..=4 => 4, 1];
+        assert_eq!(b, mat![1; 2; 3; 4]);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    #[should_panic(expected = "the range has 5 elements but a 2x3 matrix requires 6")]
+    fn mat_macro_range_length() {
+        let _: Dense<i32> = mat![range 0..5 => 2, 3];
+    }
 
     #[test]
-    fn test_transpose() {
-        let mut a = mat![1, 2, 3; 4, 5, 6];
-        assert_eq!(a[[0, 1]], 2);
+    fn eye_macro() {
+        let i = eye![u32; 3];
+        assert_eq!(i, mat![1, 0, 0; 0, 1, 0; 0, 0, 1]);
+        assert_eq!(eye![f64; 2], mat![1., 0.; 0., 1.]);
+    }
 
-        let b = a.t();
-        assert_eq!(b[[0, 1]], 4);
+    #[test]
+    fn transpose_inplace() {
+        let a = Dense::from_fn(4, 4, |i, j| (i * 4 + j) as i32);
+        let mut b = a.clone();
+        b.transpose_square_inplace().unwrap();
+        assert_eq!(b, a.transpose());
+        assert_eq!(b.trace(), a.trace());
+
+        let mut c = mat![1, 2, 3; 4, 5, 6];
+        assert_eq!(
+            c.transpose_square_inplace().unwrap_err(),
+            MatrixError::NotSquare
+        );
+        assert_eq!(c, mat![1, 2, 3; 4, 5, 6]);
+    }
 
-        a.t_mut()[[2, 0]] = 7;
-        assert_eq!(a[[0, 2]], 7);
+    #[test]
+    fn collect_col() {
+        let a = (0..5).collect::<Dense<i32>>();
+        assert_eq!(a.size(), [5, 1]);
+        assert_eq!(a, mat![0; 1; 2; 3; 4]);
+
+        let b: Dense<f64> = a.into_vec().into_iter().map(|x| x as f64 / 2.).collect();
+        assert_eq!(b, mat![0.; 0.5; 1.; 1.5; 2.]);
+
+        let empty: Dense<i32> = std::iter::empty().collect();
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_raw_matrix() {
-        let a = Dense {
-            data: vec![0, 1, 2, 3, 4, 5],
-            n: 3,
-            m: 2,
-        };
-        assert_eq!(a[[0, 0]], 0);
-        assert_eq!(a[[0, 1]], 1);
-        assert_eq!(a[[0, 2]], 2);
-        assert_eq!(a[[1, 0]], 3);
-        assert_eq!(a[[1, 1]], 4);
-        assert_eq!(a[[1, 2]], 5);
+    fn checked_from_vec() {
+        let a = Dense::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+        assert_eq!(a, mat![1, 2, 3; 4, 5, 6]);
 
-        let i: Dense<u32> = Dense::eye(3);
-        let eye3 = mat![
-            1, 0, 0;
-            0, 1, 0;
-            0, 0, 1
-        ];
+        assert_eq!(
+            Dense::from_vec(vec![1., 2., 3.], 2, 2).unwrap_err(),
+            MatrixError::Incompatibility
+        );
 
-        assert_eq!(i, eye3);
+        let empty: Dense<i32> = Dense::from_vec(vec![], 0, 4).unwrap();
+        assert_eq!(empty.size(), [0, 4]);
 
-        let j: Dense<f64> = Dense::eye(2);
-        j.assert_approx_eq(&mat![1., 0.; 0., 1.], f64::EPSILON)
+        // m * n overflows and would otherwise wrap to zero
+        assert_eq!(
+            Dense::<i32>::from_vec(vec![], 1 << (usize::BITS - 1), 2).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+    }
+
+    #[test]
+    fn resize() {
+        let mut a = mat![1, 2; 3, 4];
+        a.resize(3, 3, 0);
+        assert_eq!(a, mat![1, 2, 0; 3, 4, 0; 0, 0, 0]);
+
+        a.resize(2, 2, 9);
+        assert_eq!(a, mat![1, 2; 3, 4]);
+
+        // each dimension is handled independently
+        a.resize(1, 4, 7);
+        assert_eq!(a, mat![1, 2, 7, 7]);
+
+        a.resize(0, 0, 0);
+        assert!(a.is_empty());
+        assert_eq!(a.size(), [0, 0]);
     }
 
     #[test]
@@ -711,6 +2146,22 @@ mod tests {
         assert_eq!(f[[1, 1]], 3);
     }
 
+    #[test]
+    fn equality() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        let b = Dense {
+            data: vec![1, 2, 3, 4, 5, 6],
+            m: 3,
+            n: 2,
+        };
+        assert_ne!(a, b);
+        assert_eq!(a, a.clone());
+
+        let c = mat![1.5, 2.; 3., 4.];
+        assert_eq!(c, mat![1.5, 2.; 3., 4.]);
+        assert_ne!(c, mat![1.5, 2., 3., 4.]);
+    }
+
     #[test]
     fn approx_matrix_test() {
         let a: Dense<f64> = mat![
@@ -726,6 +2177,19 @@ mod tests {
         assert!(&a.approx_eq(&b, 0.0000002));
     }
 
+    #[test]
+    fn approx_matrix_f32() {
+        let a: Dense<f32> = mat![1.5, -2.; 0.1, 1000.];
+        let b: Dense<f32> = mat![1.5001, -2.; 0.1, 1000.];
+
+        assert!(a.approx_eq(&b, 1e-3));
+        assert!(!a.approx_eq(&b, 1e-5));
+        a.assert_approx_eq(&b, 1e-3);
+
+        let c: Dense<f32> = mat![1.5, -2., 0.1, 1000.];
+        assert!(!a.approx_eq(&c, 1.));
+    }
+
     #[test]
     fn from_vec_test() {
         let v = vec![1, 2, 3, 4];
@@ -776,6 +2240,25 @@ mod tests {
         assert_eq!(a.concatenate(b).unwrap(), ans);
     }
 
+    #[test]
+    fn vconcatenate() {
+        let a = mat![1, 2; 3, 4];
+        let b = mat![5, 6];
+        let ans = mat![1, 2; 3, 4; 5, 6];
+
+        assert_eq!(a.vconcatenate(b).unwrap(), ans);
+
+        let a = mat![1, 2, 3, 4; 5, 6, 7, 8];
+        let b = mat![9, 10, 11, 12; 13, 14, 15, 16];
+        let ans = mat![1, 2, 3, 4; 5, 6, 7, 8; 9, 10, 11, 12; 13, 14, 15, 16];
+
+        assert_eq!(a.vconcatenate(b).unwrap(), ans);
+
+        let a = mat![1, 2; 3, 4];
+        let b = mat![5; 6];
+        assert_eq!(a.vconcatenate(b).unwrap_err(), MatrixError::Incompatibility);
+    }
+
     #[test]
     fn concatenate_vec() {
         let a = mat![1, 2; 3, 4];
@@ -798,6 +2281,111 @@ mod tests {
             assert_eq!(x, mat![0, 12; 24, 30]);
         }
 
+        #[test]
+        fn matrix_add_sub() {
+            let a = mat![1, 2, 3; 4, 5, 6];
+            let b = mat![6, 5, 4; 3, 2, 1];
+            assert_eq!((a.clone() + b.clone()).unwrap(), mat![7, 7, 7; 7, 7, 7]);
+            assert_eq!((a - b).unwrap(), mat![-5, -3, -1; 1, 3, 5]);
+
+            let mut x = mat![1, 2; 3, 4];
+            x += mat![1, 1; 1, 1];
+            assert_eq!(x, mat![2, 3; 4, 5]);
+            x -= mat![2, 2; 2, 2];
+            assert_eq!(x, mat![0, 1; 2, 3]);
+        }
+
+        #[test]
+        fn matrix_add_sub_incompatible() {
+            let a = mat![1, 2, 3; 4, 5, 6];
+            let b = mat![1, 2; 3, 4; 5, 6];
+            assert_eq!(
+                (a.clone() + b.clone()).unwrap_err(),
+                MatrixError::Incompatibility
+            );
+            assert_eq!((a - b).unwrap_err(), MatrixError::Incompatibility);
+        }
+
+        #[test]
+        #[should_panic]
+        fn matrix_add_assign_incompatible() {
+            let mut a = mat![1, 2, 3; 4, 5, 6];
+            a += mat![1, 2; 3, 4; 5, 6];
+        }
+
+        #[test]
+        fn hadamard() {
+            let a = mat![1, 2; 3, 4];
+            let b = mat![5, 6; 7, 8];
+            assert_eq!(a.hadamard(&b).unwrap(), mat![5, 12; 21, 32]);
+
+            let c = mat![1, 2];
+            assert_eq!(a.hadamard(&c).unwrap_err(), MatrixError::Incompatibility);
+        }
+
+        #[test]
+        fn zip_map() {
+            let a = mat![1, 7; 3, 2];
+            let b = mat![5, 6; 0, 8];
+            let max = a.zip_map(&b, |x, y| if x > y { x } else { y });
+            assert_eq!(max.unwrap(), mat![5, 7; 3, 8]);
+
+            let c = mat![1; 2];
+            assert_eq!(
+                a.zip_map(&c, |x, y| x + y).unwrap_err(),
+                MatrixError::Incompatibility
+            );
+        }
+
+        #[test]
+        fn scalar_div() {
+            let x = mat![2, 5; 9, 12];
+            assert_eq!(x / 2, mat![1, 2; 4, 6]);
+
+            let mut x = mat![1., 3.; -5., 8.];
+            x /= 2.;
+            x.assert_approx_eq(&mat![0.5, 1.5; -2.5, 4.], f64::EPSILON);
+        }
+
+        #[test]
+        fn matrix_pow() {
+            let a = mat![1, 2; 3, 4];
+            let aaa = ((&a * &a).unwrap() * &a).unwrap();
+            assert_eq!(a.pow(3).unwrap(), aaa);
+            assert_eq!(a.pow(1).unwrap(), a);
+            assert_eq!(a.pow(0).unwrap(), mat![1, 0; 0, 1]);
+            assert_eq!(a.pow(5).unwrap(), mat![1069, 1558; 2337, 3406]);
+
+            let b = mat![1, 2, 3];
+            assert_eq!(b.pow(2).unwrap_err(), MatrixError::NotSquare);
+        }
+
+        #[test]
+        fn kronecker() {
+            let a = mat![1, 2; 3, 4];
+            let b = mat![0, 5; 6, 7];
+            let expected = mat![
+                0, 5, 0, 10;
+                6, 7, 12, 14;
+                0, 15, 0, 20;
+                18, 21, 24, 28
+            ];
+            assert_eq!(a.kron(&b), expected);
+
+            let c = mat![1, 2, 3];
+            let d = mat![1; 2];
+            assert_eq!(c.kron(&d), mat![1, 2, 3; 2, 4, 6]);
+        }
+
+        #[test]
+        fn negation() {
+            let a = mat![1, -2, 0; -4, 5, -6];
+            assert_eq!(-a, mat![-1, 2, 0; 4, -5, 6]);
+
+            let b = mat![1.5, -2.];
+            assert_eq!(-b, mat![-1.5, 2.]);
+        }
+
         #[test]
         fn row_mul() {
             let mut x = mat![0, 4; 8, 10];
@@ -856,6 +2444,24 @@ mod tests {
             assert_eq!(c.unwrap(), ans);
         }
 
+        /// the output is initialised before being written to,
+        /// `cargo +nightly miri test matrix_mul_initialised` checks for uninitialised reads
+        #[test]
+        fn matrix_mul_initialised() {
+            let a = mat![1, 3, 5; 7, 4, 6];
+            let b = mat![4, 5; 2, 8; 4, 1];
+            assert_eq!((&a * &b).unwrap(), mat![30, 34; 60, 73]);
+
+            // an inner dimension of zero never writes to the output
+            let c: Dense<f64> = Dense::zeros(3, 0);
+            let d: Dense<f64> = Dense::zeros(0, 2);
+            assert_eq!((c * d).unwrap(), Dense::zeros(3, 2));
+
+            let e: Dense<i32> = Dense::zeros(0, 4);
+            let f: Dense<i32> = Dense::zeros(4, 0);
+            assert!((e * f).unwrap().is_empty());
+        }
+
         #[test]
         fn matrix_incompatibilities() {
             let a = mat![1, 2, 3];
@@ -875,6 +2481,23 @@ mod tests {
             assert_eq!(expected, (a.t() * b).unwrap());
         }
 
+        #[test]
+        fn matrix_mul_t_matches_transpose() {
+            let a = mat![1., -2., 3.; 0.5, 4., -1.; 2., 0., 6.; -3., 1., 1.];
+            let b = mat![2., 1.; 0., -1.; 3., 5.; 1., 1.];
+
+            let expected = (a.transpose() * &b).unwrap();
+            assert_eq!((&a.t() * &b).unwrap(), expected);
+
+            // the gram matrix AᵀA without materialising the transpose
+            let gram = (a.t() * &a).unwrap();
+            assert_eq!(gram, (a.transpose() * &a).unwrap());
+            assert_eq!(gram.size(), [3, 3]);
+
+            let c = mat![1., 2.; 3., 4.];
+            assert_eq!((&a.t() * &c).unwrap_err(), MatrixError::Incompatibility);
+        }
+
         #[test]
         fn matrix_mul_m_tmut() {
             let a = mat![1, 2, 3, 4; 5, 6, 7, 8];
@@ -918,6 +2541,72 @@ mod tests {
     }
 
     #[test]
+    fn debug_print() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        let debug = format!("{a:?}");
+        assert!(debug.contains("m: 2, n: 3"));
+        assert!(debug.contains("  1  2  3\n  4  5  6"));
+        assert_ne!(debug, format!("{a}"));
+
+        let empty: Dense<f64> = Dense::new();
+        assert_eq!(format!("{empty:?}"), "Dense { m: 1, n: 0 }");
+    }
+
+    #[test]
+    fn latex() {
+        let f = mat![1.5, -2.; 0.125, 4.];
+        assert_eq!(
+            f.to_latex(2),
+            "\\begin{bmatrix}\n1.50 & -2.00 \\\\\n0.12 & 4.00\n\\end{bmatrix}"
+        );
+
+        let i = mat![1, 2; 3, 4];
+        assert_eq!(
+            i.to_latex(3),
+            r"\begin{bmatrix}
+1 & 2 \\
+3 & 4
+\end{bmatrix}"
+        );
+    }
+
+    #[test]
+    fn matrix_print_width() {
+        let i = mat![1, -20; 300, 4];
+        assert_eq!(format!("{i:6}"), "     1   -20\n   300     4");
+
+        let f = mat![0.5, 12.25];
+        assert_eq!(format!("{f:8.1}"), "     0.5    12.2");
+        assert_eq!(format!("{f:#8}"), "    0.50   12.25");
+
+        // content wider than the given width is not truncated
+        assert_eq!(format!("{i:2}"), " 1-20\n300 4");
+    }
+
+    #[test]
+    fn matrix_print_aligned() {
+        let f = mat![
+            -1.5, 10.25;
+            100., -0.5
+        ];
+
+        // the default output is unchanged
+        assert_eq!(format!("{f}"), "   -1.50   10.25\n  100.00   -0.50");
+
+        // signs stay next to their digits
+        assert_eq!(format!("{f:#}"), "   -1.50   10.25\n  100.00   -0.50");
+        assert_eq!(format!("{f:#.1}"), "   -1.5   10.2\n  100.0   -0.5");
+
+        let i = mat![-3, 12; 4, -150];
+        assert_eq!(format!("{i:#}"), "    -3    12\n     4  -150");
+
+        // values without a decimal point are aligned on where it would be
+        let n = mat![-1.5, f64::NAN; 100., -0.5];
+        assert_eq!(format!("{n:#}"), "   -1.50  NaN   \n  100.00   -0.50");
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
     fn transpose_print() {
         let a = mat![0, 1, 2; 3, 4, 5; 6, 7, 8; 9, 10, 11];
 
@@ -940,6 +2629,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn mut_trans_print() {
         let mut a = mat![0, 1, 2; 3, 4, 5; 6, 7, 8; 9, 10, 11];
 
@@ -980,6 +2670,77 @@ mod tests {
         assert_eq!(v, vec![0, 3, 1, 4, 2, 5])
     }
 
+    #[test]
+    fn col_iter() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        let mut cols = a.col_iter();
+        assert_eq!(cols.next(), Some(vec![1, 4]));
+        assert_eq!(cols.next(), Some(vec![2, 5]));
+        assert_eq!(cols.next(), Some(vec![3, 6]));
+        assert_eq!(cols.next(), None);
+
+        let data: Vec<i32> = a.col_iter().flatten().collect();
+        let t = Dense {
+            data,
+            m: a.n,
+            n: a.m,
+        };
+        assert_eq!(t, a.transpose());
+    }
+
+    #[test]
+    fn enumerate() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        let v: Vec<([usize; 2], &i32)> = a.enumerate().collect();
+        assert_eq!(
+            v,
+            vec![
+                ([0, 0], &1),
+                ([0, 1], &2),
+                ([0, 2], &3),
+                ([1, 0], &4),
+                ([1, 1], &5),
+                ([1, 2], &6)
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let a = mat![1.5, 2., 3.; 4., 5., -6.];
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#"{"data":[1.5,2.0,3.0,4.0,5.0,-6.0],"m":2,"n":3}"#);
+
+        let b: Dense<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+
+        let invalid = r#"{"data":[1,2,3],"m":2,"n":2}"#;
+        assert!(serde_json::from_str::<Dense<i32>>(invalid).is_err());
+        // m * n overflows and would otherwise wrap to zero
+        let invalid = r#"{"data":[],"m":4611686018427387904,"n":4}"#;
+        assert!(serde_json::from_str::<Dense<i32>>(invalid).is_err());
+    }
+
+    #[test]
+    fn transpose_iter() {
+        let a = mat![1, 2, 3; 4, 5, 6];
+        let t = a.t();
+
+        let rows: Vec<Vec<i32>> = (&t).into_iter().collect();
+        assert_eq!(rows, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+        let mut count = 0;
+        for (i, row) in (&t).into_iter().enumerate() {
+            assert_eq!(row.len(), 2);
+            for (j, x) in row.iter().enumerate() {
+                assert_eq!(*x, t[[i, j]]);
+            }
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+
     #[test]
     fn iters() {
         let a = mat![1, 2, 3; 4, 5, 6; 7, 8, 9];
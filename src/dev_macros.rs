@@ -38,33 +38,61 @@ macro_rules! impl_mul_matrix_ew {
 }
 
 /// a single implementation of matrix multiplication
+/// with rayon enabled the rows are filled in parallel, which needs the elements to be shared
+/// between threads, so only that impl carries the Send + Sync bounds
 macro_rules! impl_mul_matrix_single {
     ($a:ty, $b:ty) => {
+        #[cfg(not(feature = "rayon"))]
         impl<T: Numeric> Mul<$a> for $b {
-            type Output = Result<Dense<T>, MatrixError>;
+            impl_mul_matrix_body!($a);
+        }
 
-            fn mul(self, other: $a) -> Self::Output {
-                if self.n != other.m {
-                    Err(MatrixError::Incompatibility)
-                } else {
-                    let mut out: Dense<T> = Dense::with_capacity(self.m * other.n);
-                    out.m = self.m;
-                    out.n = other.n;
+        #[cfg(feature = "rayon")]
+        impl<T: Numeric + Send + Sync> Mul<$a> for $b {
+            impl_mul_matrix_body!($a);
+        }
+    };
+}
 
-                    unsafe {
-                        out.data.set_len(out.m * out.n);
-                    }
+/// the body shared by the matrix multiplication impls
+macro_rules! impl_mul_matrix_body {
+    ($a:ty) => {
+        type Output = Result<Dense<T>, MatrixError>;
 
-                    for i in 0..out.m {
-                        for j in 0..out.n {
-                            out[[i, j]] = T::ZERO;
-                            for k in 0..self.n {
-                                out[[i, j]] += self[[i, k]] * other[[k, j]]
-                            }
+        // accumulating into the output is the sum in the matrix product
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn mul(self, other: $a) -> Self::Output {
+            if self.n != other.m {
+                Err(MatrixError::Incompatibility)
+            } else {
+                let mut out: Dense<T> = Dense::zeros(self.m, other.n);
+
+                if out.data.is_empty() {
+                    return Ok(out);
+                }
+
+                // each output row is independent so they can be computed in parallel
+                let (a, b) = (&self, &other);
+                let fill_row = |(i, row): (usize, &mut [T])| {
+                    for (j, x) in row.iter_mut().enumerate() {
+                        *x = T::ZERO;
+                        for k in 0..a.n {
+                            *x += a[[i, k]] * b[[k, j]]
                         }
                     }
-                    Ok(out)
+                };
+
+                #[cfg(not(feature = "rayon"))]
+                out.data.chunks_mut(out.n).enumerate().for_each(fill_row);
+
+                #[cfg(feature = "rayon")]
+                {
+                    use rayon::prelude::*;
+                    let n = out.n;
+                    out.data.par_chunks_mut(n).enumerate().for_each(fill_row);
                 }
+
+                Ok(out)
             }
         }
     };
@@ -73,16 +101,6 @@ macro_rules! impl_mul_matrix_single {
 /// Matrix equality implementation macro for integers
 macro_rules! impl_eq_int {
     ($int:ty) => {
-        impl std::cmp::PartialEq<Dense<$int>> for Dense<$int> {
-            fn eq(&self, other: &Self) -> bool {
-                if self.m != other.m || self.n != other.n {
-                    return false;
-                } else {
-                    self.data == other.data
-                }
-            }
-        }
-
         impl std::cmp::PartialEq<Symmetric<$int>> for Symmetric<$int> {
             fn eq(&self, other: &Self) -> bool {
                 if self.n != other.n {
@@ -10,19 +10,22 @@ mod macros;
 #[macro_use]
 mod core;
 
+pub mod banded;
+mod csv;
+pub mod decomposition;
 pub mod dense;
 mod fixed;
 pub mod fraction;
 pub mod matrix;
+mod multiplication;
 pub mod numerics;
 pub mod patterns;
 pub mod solver;
+pub mod sparse;
 pub mod symmetric;
 pub mod utilities;
 
-pub use crate::core::*;
 pub use crate::dense::*;
-pub use crate::macros::*;
 // pub use crate::symmetric::*;
 // pub use crate::utilities::*;
 // pub use crate::solver::*;
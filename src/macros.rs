@@ -28,14 +28,55 @@
 /// ```
 /// where 5, 1 represent m and n, i.e. the row and column lengths respectively
 ///
+/// # example 3:
+/// A matrix can be filled row by row from a range with m * n elements
+/// ```
+/// # use numb_rs::{mat, Dense};
+/// # fn main() {
+/// let a = mat![range 0..6 => 2, 3];
+/// assert_eq!(a, mat![0, 1, 2; 3, 4, 5]);
+/// # }
+/// ```
+///
+/// # example 4:
+/// Trailing commas and semicolons are accepted so generated or copied matrices parse cleanly
+/// ```
+/// # use numb_rs::{mat, Dense};
+/// # fn main() {
+/// let a = mat![
+///     0, 1, 2,;
+///     3, 4, 5,;
+/// ];
+/// assert_eq!(a, mat![0, 1, 2; 3, 4, 5]);
+/// # }
+/// ```
+///
 #[macro_export]
 macro_rules! mat {
     // empty
     () => {
         Dense::new()
     };
-    // standard
-    ($($($item:expr),+);+) => {{
+    // fills the matrix row by row from a range, the range must have m * n elements
+    (range $range:expr => $m:expr, $n:expr) => {{
+        let v: Vec<_> = ($range).collect();
+        assert_eq!(
+            v.len(),
+            $m * $n,
+            "the range has {} elements but a {}x{} matrix requires {}",
+            v.len(),
+            $m,
+            $n,
+            $m * $n
+        );
+        Dense {
+            data: v,
+            m: $m,
+            n: $n,
+        }
+    }};
+    // standard, trailing element and row separators are allowed
+    ($($($item:expr),+ $(,)?);+ $(;)?) => {{
         let mut v = Vec::new();
         // underscored to surpress warnings
         let mut _n;
@@ -68,9 +109,27 @@ macro_rules! mat {
     }}
 }
 
+/// Creates an identity matrix of the given element type and size without a turbofish
+/// # example:
+/// ```
+/// # use numb_rs::{eye, mat, Dense};
+/// # fn main() {
+/// let a = eye![f64; 3];
+/// assert_eq!(a, Dense::<f64>::eye(3));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! eye {
+    ($t:ty; $n:expr) => {
+        Dense::<$t>::eye($n)
+    };
+}
+
 /// Creates a symmetrical matrix
 /// The aim of this macro and associated struct is for saving space
-/// # example:
+/// Only the lower triangle is given, row i must contain exactly i + 1 elements
+/// otherwise the macro will panic
+/// # example 1:
 /// ```
 /// # use numb_rs::symmetric::Symmetric;
 /// # use numb_rs::dense::Dense;
@@ -96,21 +155,42 @@ macro_rules! mat {
 /// assert_eq!(a, b);
 /// # }
 /// ```
+///
+/// # example 2:
+/// Similar to mat! a symmetric matrix can be filled with a value given only the side length n
+/// ```
+/// # use numb_rs::symmetric::Symmetric;
+/// # use numb_rs::dense::Dense;
+/// # use numb_rs::{mat, symmat};
+/// # fn main() {
+/// let a = symmat![7 => 3];
+/// assert_eq!(a, mat![7 => 3, 3]);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! symmat {
     ($($($item:expr),+);+) => {{
         let mut v = Vec::new();
         let mut n = 0;
         $(
-        $({
-            v.push($item);
-        })*
+            let mut _len = 0;
+            $({
+                v.push($item);
+                _len += 1;
+            })*
+            assert_eq!(
+                _len,
+                n + 1,
+                "row {} of a symmetric matrix must have {} elements",
+                n,
+                n + 1
+            );
             n += 1;
         )*
 
         Symmetric{
         data: v,
-        n: n,
+        n,
         m: n,
         }
     }};
@@ -28,8 +28,32 @@ pub enum MatrixError {
     Inconsistent,
     /// Numeric Instability, where rounding of floating point numbers may result in incorrect answers
     NumericInstability,
+    /// The matrix was expected to be symmetric
+    NotSymmetric,
+    /// The matrix was expected to be positive definite, as required by Cholesky decomposition
+    NotPositiveDefinite,
+    /// The operation is only defined for square matrices, such as the trace, determinant or inverse
+    NotSquare,
 }
 
+impl std::fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MatrixError::Error(msg) => write!(f, "{msg}"),
+            MatrixError::Incompatibility => write!(f, "the matrix dimensions are incompatible"),
+            MatrixError::Singularity => write!(f, "the matrix is singular"),
+            MatrixError::NonUniqueSolution => write!(f, "the system has no unique solution"),
+            MatrixError::Inconsistent => write!(f, "the system is inconsistent"),
+            MatrixError::NumericInstability => write!(f, "the result is numerically unstable"),
+            MatrixError::NotSymmetric => write!(f, "the matrix is not symmetric"),
+            MatrixError::NotPositiveDefinite => write!(f, "the matrix is not positive definite"),
+            MatrixError::NotSquare => write!(f, "the matrix is not square"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
 //◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼ # TRAITS ◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼◼
 pub trait Matrix {
     /// The type of matrix elements
@@ -82,3 +106,35 @@ pub trait IntoTransposeMut<'a>: Matrix + IntoTranspose<'a> {
 
     fn t_mut(&'a mut self) -> Self::TransposeViewMut;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            MatrixError::NotSquare.to_string(),
+            "the matrix is not square"
+        );
+        assert_eq!(
+            MatrixError::Error("bad input".to_string()).to_string(),
+            "bad input"
+        );
+        assert_eq!(
+            format!("{}", MatrixError::Singularity),
+            "the matrix is singular"
+        );
+    }
+
+    #[test]
+    fn boxed_error() {
+        fn trace() -> Result<f64, Box<dyn std::error::Error>> {
+            let a = mat![1., 2., 3.; 4., 5., 6.];
+            Ok(a.trace()?)
+        }
+
+        let err = trace().unwrap_err();
+        assert_eq!(err.to_string(), "the matrix is not square");
+    }
+}
@@ -0,0 +1,220 @@
+//! Alternative matrix multiplication algorithms for dense matrices
+
+use crate::dense::{dot, Dense};
+use crate::matrix::{Concatenate, MatrixError};
+use crate::numerics::Numeric;
+use std::ops::Mul;
+
+/// Below this size Strassen multiplication falls back to naive multiplication
+const STRASSEN_THRESHOLD: usize = 64;
+
+impl<T: Numeric> Dense<T> {
+    /// Matrix multiplication using Strassen's algorithm
+    /// The matrices are padded with zeros to a square with sides of the next power of two
+    /// and recursively split into quadrants until they are small enough for naive multiplication
+    /// Unsigned element types use naive multiplication as the quadrant differences can be negative
+    pub fn strassen_mul(&self, other: &Dense<T>) -> Result<Dense<T>, MatrixError>
+    where
+        for<'a> &'a Dense<T>: Mul<&'a Dense<T>, Output = Result<Dense<T>, MatrixError>>,
+    {
+        if self.n != other.m {
+            return Err(MatrixError::Incompatibility);
+        }
+        if !T::SIGNED {
+            return self * other;
+        }
+
+        let size = self.m.max(self.n).max(other.n).next_power_of_two();
+        let pad = |a: &Dense<T>| {
+            Dense::from_fn(size, size, |i, j| {
+                if i < a.m && j < a.n {
+                    a[[i, j]]
+                } else {
+                    T::ZERO
+                }
+            })
+        };
+
+        let product = strassen(&pad(self), &pad(other))?;
+        product.submatrix(0..self.m, 0..other.n)
+    }
+
+    /// Matrix multiplication with the loops tiled into blocks to improve cache locality
+    ///
+    /// # Panics
+    /// if the block size is zero
+    pub fn mul_blocked(&self, other: &Dense<T>, block: usize) -> Result<Dense<T>, MatrixError> {
+        assert!(block > 0, "block size must be greater than zero");
+        if self.n != other.m {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let mut out: Dense<T> = Dense::zeros(self.m, other.n);
+
+        for ii in (0..self.m).step_by(block) {
+            let i_end = (ii + block).min(self.m);
+            for kk in (0..self.n).step_by(block) {
+                let k_end = (kk + block).min(self.n);
+                for jj in (0..other.n).step_by(block) {
+                    let j_end = (jj + block).min(other.n);
+
+                    for i in ii..i_end {
+                        for k in kk..k_end {
+                            let a = self[[i, k]];
+                            for j in jj..j_end {
+                                out[[i, j]] += a * other[[k, j]];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Multiplies by a vector given as a flat slice, returning a vector of length m
+    pub fn mul_vec(&self, v: &[T]) -> Result<Vec<T>, MatrixError> {
+        if v.len() != self.n {
+            return Err(MatrixError::Incompatibility);
+        }
+        self.into_iter().map(|row| dot(row, v)).collect()
+    }
+}
+
+/// recursive step of Strassen multiplication for square matrices with sides of a power of two
+fn strassen<T: Numeric>(a: &Dense<T>, b: &Dense<T>) -> Result<Dense<T>, MatrixError>
+where
+    for<'a> &'a Dense<T>: Mul<&'a Dense<T>, Output = Result<Dense<T>, MatrixError>>,
+{
+    let size = a.n;
+    if size <= STRASSEN_THRESHOLD {
+        return a * b;
+    }
+
+    let h = size / 2;
+    let (a11, a12) = (a.submatrix(0..h, 0..h)?, a.submatrix(0..h, h..size)?);
+    let (a21, a22) = (a.submatrix(h..size, 0..h)?, a.submatrix(h..size, h..size)?);
+    let (b11, b12) = (b.submatrix(0..h, 0..h)?, b.submatrix(0..h, h..size)?);
+    let (b21, b22) = (b.submatrix(h..size, 0..h)?, b.submatrix(h..size, h..size)?);
+
+    let add = |x: &Dense<T>, y: &Dense<T>| x.zip_map(y, |p, q| p + q);
+    let sub = |x: &Dense<T>, y: &Dense<T>| x.zip_map(y, |p, q| p - q);
+
+    let m1 = strassen(&add(&a11, &a22)?, &add(&b11, &b22)?)?;
+    let m2 = strassen(&add(&a21, &a22)?, &b11)?;
+    let m3 = strassen(&a11, &sub(&b12, &b22)?)?;
+    let m4 = strassen(&a22, &sub(&b21, &b11)?)?;
+    let m5 = strassen(&add(&a11, &a12)?, &b22)?;
+    let m6 = strassen(&sub(&a21, &a11)?, &add(&b11, &b12)?)?;
+    let m7 = strassen(&sub(&a12, &a22)?, &add(&b21, &b22)?)?;
+
+    let c11 = add(&sub(&add(&m1, &m4)?, &m5)?, &m7)?;
+    let c12 = add(&m3, &m5)?;
+    let c21 = add(&m2, &m4)?;
+    let c22 = add(&add(&sub(&m1, &m2)?, &m3)?, &m6)?;
+
+    c11.concatenate(c12)?.vconcatenate(c21.concatenate(c22)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a deterministic matrix with a mix of positive and negative values
+    fn sample(m: usize, n: usize, seed: i64) -> Dense<i64> {
+        Dense::from_fn(m, n, |i, j| {
+            ((i as i64 * 31 + j as i64 * 17 + seed) % 23) - 11
+        })
+    }
+
+    #[test]
+    fn strassen_small() {
+        let a = mat![1, 3, 5; 7, 4, 6];
+        let b = mat![4, 5; 2, 8; 4, 1];
+        assert_eq!(a.strassen_mul(&b).unwrap(), mat![30, 34; 60, 73]);
+
+        let c = mat![1, 2, 3];
+        assert_eq!(
+            a.strassen_mul(&c).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+    }
+
+    #[test]
+    fn strassen_matches_naive() {
+        // padded to 256 so the recursion runs two levels deep
+        let a = sample(130, 130, 3);
+        let b = sample(130, 130, 7);
+        assert_eq!(a.strassen_mul(&b).unwrap(), (&a * &b).unwrap());
+
+        let c = sample(70, 90, 1);
+        let d = sample(90, 40, 5);
+        assert_eq!(c.strassen_mul(&d).unwrap(), (&c * &d).unwrap());
+    }
+
+    #[test]
+    fn strassen_unsigned() {
+        // large enough to recurse, where the quadrant differences would underflow
+        let a = Dense::from_fn(65, 65, |i, j| ((i * 7 + j * 3) % 11) as u32);
+        let b = Dense::from_fn(65, 65, |i, j| ((i * 5 + j * 13) % 17) as u32);
+        assert_eq!(a.strassen_mul(&b).unwrap(), (&a * &b).unwrap());
+    }
+
+    #[test]
+    fn blocked_matches_naive() {
+        let a = sample(64, 64, 3);
+        let b = sample(64, 64, 7);
+        let expected = (&a * &b).unwrap();
+        for block in [1, 7, 16, 64, 100] {
+            assert_eq!(a.mul_blocked(&b, block).unwrap(), expected);
+        }
+
+        let c = sample(33, 20, 1);
+        let d = sample(20, 45, 5);
+        assert_eq!(c.mul_blocked(&d, 8).unwrap(), (&c * &d).unwrap());
+
+        assert_eq!(
+            c.mul_blocked(&c, 8).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn blocked_zero_block() {
+        let a = sample(4, 4, 3);
+        let _ = a.mul_blocked(&a, 0);
+    }
+
+    #[test]
+    fn mul_vec_matches_product() {
+        let a = sample(30, 17, 3);
+        let v = sample(17, 1, 7);
+        assert_eq!(a.mul_vec(&v.data).unwrap(), (&a * &v).unwrap().data);
+
+        assert_eq!(mat![1, 2; 3, 4].mul_vec(&[5, 6]).unwrap(), vec![17, 39]);
+        assert_eq!(
+            a.mul_vec(&[1, 2, 3]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_matches_sequential() {
+        let a = sample(150, 120, 3);
+        let b = sample(120, 90, 7);
+        // a single block performs the multiplication sequentially in the same order
+        assert_eq!((&a * &b).unwrap(), a.mul_blocked(&b, 150).unwrap());
+
+        let c = a.map(|x| x as f64 / 7.);
+        let d = b.map(|x| x as f64 / 3.);
+        let product = (&c * &d).unwrap();
+        let sequential = c.mul_blocked(&d, 150).unwrap();
+        assert!(product
+            .data
+            .iter()
+            .zip(&sequential.data)
+            .all(|(x, y)| x.to_bits() == y.to_bits()));
+    }
+}
@@ -31,6 +31,9 @@ pub trait Numeric:
     const ONE: Self;
     const TWO: Self;
 
+    /// whether the type can represent negative values
+    const SIGNED: bool = true;
+
     #[inline]
     fn is_even(self) -> bool {
         self % Self::TWO == Self::ZERO
@@ -46,7 +49,14 @@ impl_multiple!(Numeric => {
     const ZERO: Self = 0;
     const ONE: Self = 1;
     const TWO: Self = 2;
-} for i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+} for i8 i16 i32 i64 i128 isize);
+
+impl_multiple!(Numeric => {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    const TWO: Self = 2;
+    const SIGNED: bool = false;
+} for u8 u16 u32 u64 u128 usize);
 
 impl Numeric for f32 {
     const ZERO: Self = 0f32;
@@ -60,8 +70,24 @@ impl Numeric for f64 {
 }
 
 /// trait used for Primitive number classification of Integers
-pub trait SignedInt: Neg<Output = Self> + Integer {}
-impl_multiple!(SignedInt for i8 i16 i32 i64 i128 isize);
+pub trait SignedInt: Neg<Output = Self> + Integer {
+    fn abs(self) -> Self;
+
+    /// returns -1, 0 or 1 depending on the sign of the value
+    fn signum(self) -> Self;
+}
+
+impl_multiple!(SignedInt => {
+    #[inline]
+    fn abs(self) -> Self {
+        Self::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        Self::signum(self)
+    }
+} for i8 i16 i32 i64 i128 isize);
 
 pub trait Unsigned: Integer {}
 impl_multiple!(Unsigned for u8 u16 u32 u64 u128 usize);
@@ -76,6 +102,14 @@ pub trait Float: Numeric + Neg<Output = Self> {
 
     fn abs(self) -> Self;
 
+    fn sqrt(self) -> Self;
+
+    fn powi(self, n: i32) -> Self;
+
+    fn powf(self, p: Self) -> Self;
+
+    fn exp(self) -> Self;
+
     fn from_f32(f: f32) -> Self;
 }
 
@@ -87,6 +121,26 @@ impl Float for f64 {
         f64::abs(self)
     }
 
+    #[inline]
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+
+    #[inline]
+    fn powf(self, p: Self) -> Self {
+        f64::powf(self, p)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
     #[inline]
     fn from_f32(f: f32) -> Self {
         f as Self
@@ -101,6 +155,26 @@ impl Float for f32 {
         f32::abs(self)
     }
 
+    #[inline]
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        f32::powi(self, n)
+    }
+
+    #[inline]
+    fn powf(self, p: Self) -> Self {
+        f32::powf(self, p)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        f32::exp(self)
+    }
+
     #[inline]
     fn from_f32(f: f32) -> Self {
         f
@@ -142,6 +216,57 @@ pub fn lcm<T: Unsigned>(a: T, b: T) -> T {
 mod tests {
     use super::*;
 
+    fn largest_magnitude<T: SignedInt>(v: &[T]) -> T {
+        v.iter()
+            .fold(T::ZERO, |max, &x| if x.abs() > max { x.abs() } else { max })
+    }
+
+    #[test]
+    fn signed_abs() {
+        assert_eq!(SignedInt::abs(-5i8), 5);
+        assert_eq!(SignedInt::abs(7i16), 7);
+        assert_eq!(SignedInt::abs(0i32), 0);
+        assert_eq!(SignedInt::abs(-9_000_000_000i64), 9_000_000_000);
+        assert_eq!(SignedInt::abs(-3i128), 3);
+        assert_eq!(SignedInt::abs(-4isize), 4);
+
+        assert_eq!(SignedInt::signum(-12i32), -1);
+        assert_eq!(SignedInt::signum(0i64), 0);
+        assert_eq!(SignedInt::signum(3isize), 1);
+
+        assert_eq!(largest_magnitude(&[3i32, -8, 5, -2]), 8);
+    }
+
+    #[test]
+    fn float_sqrt() {
+        fn root<T: Float>(x: T) -> T {
+            x.sqrt()
+        }
+
+        assert_eq!(Float::sqrt(4.0f64), 2.0);
+        assert_eq!(root(2.25f64), 1.5);
+        assert_eq!(root(9.0f32), 3.0);
+        assert!(root(-1.0f32).is_nan());
+    }
+
+    #[test]
+    fn float_powers() {
+        fn cube<T: Float>(x: T) -> T {
+            x.powi(3)
+        }
+
+        assert_eq!(cube(2.0f64), 8.0);
+        assert_eq!(cube(-3.0f32), -27.0);
+        assert_eq!(Float::powi(2.0f64, -2), 0.25);
+
+        assert_eq!(Float::powf(16.0f64, 0.5), 4.0);
+        assert!((Float::powf(8.0f32, 1. / 3.) - 2.0).abs() < 1e-6);
+
+        assert_eq!(Float::exp(0.0f64), 1.0);
+        assert!((Float::exp(1.0f64) - std::f64::consts::E).abs() < f64::EPSILON);
+        assert!((Float::exp(2.0f32) - 7.389_056).abs() < 1e-5);
+    }
+
     #[test]
     fn gcd_test() {
         let a: u32 = 21;
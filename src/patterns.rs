@@ -1,4 +1,4 @@
-/// Module for implementing numeric patterns
+//! Module for implementing numeric patterns
 
 pub struct TriangularNumbers {
     n: usize,
@@ -1,9 +1,8 @@
 use crate::dense::Dense;
-use crate::matrix::{Concatenate, Matrix, MatrixError, RowOps};
+use crate::matrix::{Concatenate, IntoTranspose, Matrix, MatrixError, RowOps};
 use crate::numerics::Float;
 
-/// TODO: Cholesky decomposition for positive definite matrices
-/// TODO: Remove the column limit and augment the indentity matrix to find the inverse
+// TODO: Remove the column limit and augment the indentity matrix to find the inverse
 
 /// Gauss-Jordan Elimination to solve a system of linear equations where Ax=B
 /// Applies partial pivoting for numerical stability
@@ -91,6 +90,266 @@ pub fn solve_dense_vec<T: Float>(a: Dense<T>, b: &[T]) -> Result<Vec<T>, MatrixE
     solve_augmented(aug)
 }
 
+impl<T: Float> Dense<T> {
+    /// Inverse of a square matrix using Gauss-Jordan elimination with partial pivoting
+    /// The matrix is augmented with the identity matrix which is transformed into the inverse
+    pub fn inv(&self) -> Result<Dense<T>, MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let n = self.n;
+        let mut identity = Dense::zeros(n, n);
+        for i in 0..n {
+            identity[[i, i]] = T::ONE;
+        }
+
+        // pivots smaller than this are treated as zero
+        let tolerance = T::EPSILON * T::from_f32(n as f32) * largest_magnitude(self);
+
+        let mut augmented = self.clone().concatenate(identity)?;
+
+        for k in 0..n {
+            let mut pivot = k;
+            let mut max = augmented[[k, k]].abs();
+            for i in k + 1..n {
+                let check = augmented[[i, k]].abs();
+                if check > max {
+                    max = check;
+                    pivot = i;
+                }
+            }
+
+            if max <= tolerance {
+                return Err(MatrixError::Singularity);
+            }
+
+            if pivot != k {
+                augmented.swap_rows(k, pivot);
+            }
+
+            let scale = T::ONE / augmented[[k, k]];
+            augmented.scale_row(k, scale);
+
+            for i in 0..n {
+                if i != k {
+                    let scale = augmented[[i, k]];
+                    augmented.add_rows(i, k, -scale);
+                }
+            }
+        }
+
+        let mut out: Dense<T> = Dense::with_capacity(n * n);
+        out.m = n;
+        out.n = n;
+        for i in 0..n {
+            out.data.extend_from_slice(&augmented[i][n..]);
+        }
+        Ok(out)
+    }
+
+    /// Solves Ax = b for each column in b using the LU decomposition of A
+    pub fn solve(&self, b: &Dense<T>) -> Result<Dense<T>, MatrixError> {
+        if b.m != self.m {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let (l, u, perm) = self.lu()?;
+        let n = self.n;
+
+        let tolerance = T::EPSILON * T::from_f32(n as f32) * largest_magnitude(self);
+        if (0..n).any(|i| u[[i, i]].abs() <= tolerance) {
+            return Err(MatrixError::Singularity);
+        }
+
+        let mut x: Dense<T> = Dense::zeros(n, b.n);
+
+        for c in 0..b.n {
+            // forward substitution Ly = Pb, y is stored in x
+            for i in 0..n {
+                let mut sum = b[[perm[i], c]];
+                for k in 0..i {
+                    sum -= l[[i, k]] * x[[k, c]];
+                }
+                x[[i, c]] = sum;
+            }
+
+            // back substitution Ux = y
+            for i in (0..n).rev() {
+                let mut sum = x[[i, c]];
+                for k in i + 1..n {
+                    sum -= u[[i, k]] * x[[k, c]];
+                }
+                x[[i, c]] = sum / u[[i, i]];
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// The number of linearly independent rows found by Gaussian elimination
+    /// pivots with a magnitude not greater than the tolerance are treated as zero
+    pub fn rank(&self, tolerance: T) -> usize {
+        self.clone().eliminate(tolerance).len()
+    }
+
+    /// Reduced row echelon form
+    /// each pivot is normalised to one and all other elements in the pivot column are zero
+    pub fn rref(&self) -> Dense<T> {
+        let mut a = self.clone();
+        let pivots = a.forward_eliminate();
+
+        // the elements below each pivot are already zero so only the rows above need clearing
+        for (row, &col) in pivots.iter().enumerate() {
+            let scale = T::ONE / a[[row, col]];
+            a.scale_row(row, scale);
+            a[[row, col]] = T::ONE;
+
+            for i in 0..row {
+                let scale = a[[i, col]];
+                a.add_rows(i, row, -scale);
+                a[[i, col]] = T::ZERO;
+            }
+        }
+
+        a
+    }
+
+    /// Gaussian forward elimination with partial pivoting performed in place, leaving the matrix
+    /// in row echelon form. returns the column index of each pivot, columns without a pivot
+    /// are dependent on the pivot columns before them
+    pub fn forward_eliminate(&mut self) -> Vec<usize> {
+        let size = if self.m > self.n { self.m } else { self.n };
+        let tolerance = T::EPSILON * T::from_f32(size as f32) * largest_magnitude(self);
+        self.eliminate(tolerance)
+    }
+
+    /// forward elimination treating pivots with a magnitude not greater than the tolerance as zero
+    fn eliminate(&mut self, tolerance: T) -> Vec<usize> {
+        let mut pivots = vec![];
+
+        for col in 0..self.n {
+            let row = pivots.len();
+            if row == self.m {
+                break;
+            }
+
+            let mut pivot = row;
+            let mut max = self[[row, col]].abs();
+            for i in row + 1..self.m {
+                let check = self[[i, col]].abs();
+                if check > max {
+                    max = check;
+                    pivot = i;
+                }
+            }
+
+            if max <= tolerance {
+                // free column, clear any rounding residue below the current row
+                for i in row..self.m {
+                    self[[i, col]] = T::ZERO;
+                }
+                continue;
+            }
+
+            self.swap_rows(row, pivot);
+            for i in row + 1..self.m {
+                let scale = self[[i, col]] / self[[row, col]];
+                self.add_rows(i, row, -scale);
+                self[[i, col]] = T::ZERO;
+            }
+            pivots.push(col);
+        }
+
+        pivots
+    }
+}
+
+impl Dense<f64> {
+    /// Least squares solution minimising ||Ax - b|| for each column in b
+    /// Uses the QR decomposition of A so that x is the solution of Rx = Qᵀb,
+    /// only valid for systems with at least as many equations as unknowns
+    pub fn lstsq(&self, b: &Dense<f64>) -> Result<Dense<f64>, MatrixError> {
+        if b.m != self.m {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let (q, r) = self.qr()?;
+        let mut x = (q.t() * b)?;
+        let n = self.n;
+
+        // back substitution Rx = Qᵀb, R is non-singular as qr rejects dependent columns
+        for c in 0..b.n {
+            for i in (0..n).rev() {
+                let mut sum = x[[i, c]];
+                for k in i + 1..n {
+                    sum -= r[[i, k]] * x[[k, c]];
+                }
+                x[[i, c]] = sum / r[[i, i]];
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Solves Lx = b by forward substitution, only the lower triangle of the matrix is read
+    pub fn solve_lower(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.check_triangular(b)?;
+        let mut x = vec![0.; self.n];
+        for i in 0..self.n {
+            let sum: f64 = (0..i).map(|k| self[[i, k]] * x[k]).sum();
+            x[i] = (b[i] - sum) / self[[i, i]];
+        }
+        Ok(x)
+    }
+
+    /// Solves Ux = b by back substitution, only the upper triangle of the matrix is read
+    pub fn solve_upper(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.check_triangular(b)?;
+        let mut x = vec![0.; self.n];
+        for i in (0..self.n).rev() {
+            let sum: f64 = (i + 1..self.n).map(|k| self[[i, k]] * x[k]).sum();
+            x[i] = (b[i] - sum) / self[[i, i]];
+        }
+        Ok(x)
+    }
+
+    /// a triangular system must be square, match the length of b and have no zeros on the diagonal
+    fn check_triangular(&self, b: &[f64]) -> Result<(), MatrixError> {
+        if self.m != self.n {
+            return Err(MatrixError::NotSquare);
+        }
+        if b.len() != self.n {
+            return Err(MatrixError::Incompatibility);
+        }
+        if (0..self.n).any(|i| self[[i, i]] == 0.) {
+            return Err(MatrixError::Singularity);
+        }
+        Ok(())
+    }
+
+    /// The condition number in the 1-norm, ||A|| * ||A⁻¹||, an estimate of how much errors in
+    /// b are amplified when solving Ax = b. Large values indicate a nearly singular matrix
+    pub fn cond(&self) -> Result<f64, MatrixError> {
+        let inverse = self.inv()?;
+        Ok(one_norm(self) * one_norm(&inverse))
+    }
+}
+
+/// the maximum absolute column sum
+fn one_norm(a: &Dense<f64>) -> f64 {
+    a.col_iter()
+        .map(|col| col.iter().map(|x| x.abs()).sum::<f64>())
+        .fold(0., f64::max)
+}
+
+/// the largest absolute value of all elements in the matrix
+pub(crate) fn largest_magnitude<T: Float>(a: &Dense<T>) -> T {
+    a.data
+        .iter()
+        .fold(T::ZERO, |max, x| if x.abs() > max { x.abs() } else { max })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +515,253 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn inverse() {
+        let matrices = vec![
+            mat![4., 7.; 2., 6.],
+            mat![
+                0., 2., -1.;
+                3., -2., 1.;
+                3., 2., -1.5
+            ],
+            mat![
+                1., -2., 1., 7.;
+                2., -1., -3., -2.;
+                7., 4., -2., 14.;
+                8., 2., -3., -3.
+            ],
+        ];
+
+        for a in matrices {
+            let identity: Dense<f64> = Dense::eye(a.n);
+            let inverse = a.inv().unwrap();
+            (&a * &inverse).unwrap().assert_approx_eq(&identity, 1e-12);
+            (&inverse * &a).unwrap().assert_approx_eq(&identity, 1e-12);
+        }
+
+        let a = mat![4., 7.; 2., 6.];
+        a.inv()
+            .unwrap()
+            .assert_approx_eq(&mat![0.6, -0.7; -0.2, 0.4], 1e-12);
+    }
+
+    #[test]
+    fn inverse_err() {
+        let singular = mat![
+            1., 2., 3.;
+            4., 5., 6.;
+            7., 8., 9.
+        ];
+        assert_eq!(singular.inv().unwrap_err(), MatrixError::Singularity);
+
+        let rectangular = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(rectangular.inv().unwrap_err(), MatrixError::NotSquare);
+    }
+
+    #[test]
+    fn lu_solve() {
+        let a = mat![
+            1., -2., 1.;
+            2., 1., -3.;
+            4., -7., 1.
+        ];
+        let b = mat![
+            0., 1.;
+            5., -2.;
+            -1., 8.
+        ];
+
+        let x = a.solve(&b).unwrap();
+        assert_eq!(x.size(), [3, 2]);
+
+        let residual = ((&a * &x).unwrap() - b).unwrap();
+        residual.assert_approx_eq(&mat![0. => 3, 2], 1e-12);
+
+        x.assert_approx_eq(&mat![3., -3.; 2., -3.2; 1., -2.4], 1e-12);
+    }
+
+    #[test]
+    fn lstsq() {
+        // fitting y = c + mx to four noisy points
+        let a = mat![1., 0.; 1., 1.; 1., 2.; 1., 3.];
+        let b = mat![1.1; 2.9; 5.2; 6.8];
+
+        let x = a.lstsq(&b).unwrap();
+        x.assert_approx_eq(&mat![1.09; 1.94], 1e-12);
+
+        let residual = |x: &Dense<f64>| ((&a * x).unwrap() - b.clone()).unwrap().norm_sq();
+        let best = residual(&x);
+        for (dc, dm) in [(1e-3, 0.), (0., 1e-3), (-1e-3, 1e-3)] {
+            let perturbed = (x.clone() + mat![dc; dm]).unwrap();
+            assert!(residual(&perturbed) > best);
+        }
+
+        // a square system is solved exactly
+        let c = mat![2., 1.; 1., 3.];
+        let d = mat![3.; 5.];
+        c.lstsq(&d)
+            .unwrap()
+            .assert_approx_eq(&mat![0.8; 1.4], 1e-12);
+
+        let wide = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(
+            wide.lstsq(&mat![1.; 2.]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+        assert_eq!(a.lstsq(&d).unwrap_err(), MatrixError::Incompatibility);
+    }
+
+    #[test]
+    fn cond() {
+        let eye: Dense<f64> = Dense::eye(4);
+        assert_eq!(eye.cond().unwrap(), 1.);
+
+        let a = mat![4., -2.; 1., 1.];
+        // ||A|| = 5 and ||A⁻¹|| = 1
+        a.cond().unwrap().assert_approx_eq(&5., 1e-12);
+
+        let near_singular = mat![1., 1.; 1., 1. + 1e-10];
+        assert!(near_singular.cond().unwrap() > 1e9);
+
+        let singular = mat![1., 2.; 2., 4.];
+        assert_eq!(singular.cond().unwrap_err(), MatrixError::Singularity);
+
+        let rectangular = mat![1., 2., 3.; 4., 5., 6.];
+        assert_eq!(rectangular.cond().unwrap_err(), MatrixError::NotSquare);
+    }
+
+    #[test]
+    fn triangular_solves() {
+        let l = mat![
+            2., 0., 0.;
+            -1., 3., 0.;
+            4., 1., -2.
+        ];
+        // 2x = 4, -x + 3y = 7, 4x + y - 2z = 1
+        assert_eq!(l.solve_lower(&[4., 7., 1.]).unwrap(), vec![2., 3., 5.]);
+
+        let u = mat![
+            1., 2., -1.;
+            0., 2., 4.;
+            0., 0., 5.
+        ];
+        // 5z = 10, 2y + 4z = 6, x + 2y - z = 0
+        assert_eq!(u.solve_upper(&[0., 6., 10.]).unwrap(), vec![4., -1., 2.]);
+
+        let singular = mat![1., 0.; 2., 0.];
+        assert_eq!(
+            singular.solve_lower(&[1., 2.]).unwrap_err(),
+            MatrixError::Singularity
+        );
+        assert_eq!(
+            l.solve_upper(&[1., 2.]).unwrap_err(),
+            MatrixError::Incompatibility
+        );
+        assert_eq!(
+            mat![1., 2., 3.].solve_upper(&[1.]).unwrap_err(),
+            MatrixError::NotSquare
+        );
+    }
+
+    #[test]
+    fn forward_eliminate() {
+        // the second column is twice the first
+        let mut a = mat![
+            1., 2., 3.;
+            2., 4., 7.;
+            1., 2., 5.
+        ];
+        let pivots = a.forward_eliminate();
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(pivots.len(), a.rank(1e-12));
+
+        // row echelon form, zeros below each pivot and a zero row at the bottom
+        a.assert_approx_eq(&mat![2., 4., 7.; 0., 0., 1.5; 0., 0., 0.], 1e-12);
+
+        let mut b = mat![4., -2.; 1., 1.];
+        assert_eq!(b.forward_eliminate(), vec![0, 1]);
+        b.assert_approx_eq(&mat![4., -2.; 0., 1.5], 1e-12);
+    }
+
+    #[test]
+    fn lu_solve_err() {
+        let a = mat![1., 2.; 3., 4.];
+        let b = mat![1.; 2.; 3.];
+        assert_eq!(a.solve(&b).unwrap_err(), MatrixError::Incompatibility);
+
+        let a = mat![1., 2., 3.; 4., 5., 6.];
+        let b = mat![1.; 2.];
+        assert_eq!(a.solve(&b).unwrap_err(), MatrixError::NotSquare);
+
+        let a = mat![1., 2.; 2., 4.];
+        let b = mat![1.; 2.];
+        assert_eq!(a.solve(&b).unwrap_err(), MatrixError::Singularity);
+
+        // only singular up to rounding error
+        let a = mat![1., 2., 3.; 4., 5., 6.; 7., 8., 9.];
+        assert_eq!(
+            a.solve(&mat![1.; 2.; 3.]).unwrap_err(),
+            MatrixError::Singularity
+        );
+    }
+
+    #[test]
+    fn rank() {
+        let full = mat![
+            2., -1., 0.;
+            -1., 2., -1.;
+            0., -1., 2.
+        ];
+        assert_eq!(full.rank(1e-10), 3);
+
+        // the third row is the sum of the first two
+        let deficient = mat![
+            1., 2., 3.;
+            4., 5., 6.;
+            5., 7., 9.
+        ];
+        assert_eq!(deficient.rank(1e-10), 2);
+
+        let zero: Dense<f64> = Dense::zeros(3, 3);
+        assert_eq!(zero.rank(1e-10), 0);
+
+        let wide = mat![1., 2., 3., 4.; 2., 4., 6., 8.];
+        assert_eq!(wide.rank(1e-10), 1);
+    }
+
+    #[test]
+    fn rref() {
+        let invertible = mat![
+            2., 1., -1.;
+            -3., -1., 2.;
+            -2., 1., 2.
+        ];
+        invertible.rref().assert_approx_eq(&Dense::eye(3), 1e-12);
+
+        // the second column is twice the first so it has no pivot
+        let singular = mat![
+            1., 2., 1.;
+            2., 4., 0.;
+            3., 6., 1.
+        ];
+        let expected = mat![
+            1., 2., 0.;
+            0., 0., 1.;
+            0., 0., 0.
+        ];
+        singular.rref().assert_approx_eq(&expected, 1e-12);
+
+        let augmented = mat![
+            1., 2., -1., -4.;
+            2., 3., -1., -11.;
+            -2., 0., -3., 22.
+        ];
+        let expected = mat![
+            1., 0., 0., -8.;
+            0., 1., 0., 1.;
+            0., 0., 1., -2.
+        ];
+        augmented.rref().assert_approx_eq(&expected, 1e-12);
+    }
 }
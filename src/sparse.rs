@@ -0,0 +1,225 @@
+//! module for the sparse matrix type
+
+use crate::dense::Dense;
+use crate::matrix::{Matrix, MatrixError};
+use crate::numerics::Numeric;
+use std::ops::{Index, Mul};
+
+/// A sparse matrix stored in coordinate (COO) format
+/// only the nonzero elements are stored as (row, column, value) triples
+/// # example:
+/// ```
+/// # use numb_rs::sparse::Sparse;
+/// let mut a: Sparse<i32> = Sparse::new(1000, 1000);
+/// a.insert(3, 999, 7);
+///
+/// assert_eq!(a[[3, 999]], 7);
+/// assert_eq!(a[[999, 3]], 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sparse<T> {
+    /// (row, column, value) triples of the stored elements
+    pub data: Vec<(usize, usize, T)>,
+    /// number of rows
+    pub m: usize,
+    /// number of columns
+    pub n: usize,
+    /// returned by reference for elements which are not stored
+    zero: T,
+}
+
+impl<T: Numeric> Sparse<T> {
+    /// returns an m x n matrix with no stored elements
+    pub fn new(m: usize, n: usize) -> Self {
+        Sparse {
+            data: Vec::new(),
+            m,
+            n,
+            zero: T::ZERO,
+        }
+    }
+
+    /// sets the element at i, j replacing any existing value
+    /// setting an element to zero removes it from storage
+    pub fn insert(&mut self, i: usize, j: usize, value: T) {
+        assert!(
+            i < self.m && j < self.n,
+            "index [{}, {}] out of range for a {}x{} matrix",
+            i,
+            j,
+            self.m,
+            self.n
+        );
+
+        let existing = self.data.iter().position(|&(r, c, _)| r == i && c == j);
+        match (existing, value == T::ZERO) {
+            (Some(k), true) => {
+                self.data.swap_remove(k);
+            }
+            (Some(k), false) => self.data[k].2 = value,
+            (None, true) => {}
+            (None, false) => self.data.push((i, j, value)),
+        }
+    }
+}
+
+/// only the nonzero elements of the dense matrix are stored
+impl<T: Numeric> std::convert::From<Dense<T>> for Sparse<T> {
+    fn from(mat: Dense<T>) -> Sparse<T> {
+        let mut new = Sparse::new(mat.m, mat.n);
+        new.data = mat
+            .enumerate()
+            .filter(|(_, &x)| x != T::ZERO)
+            .map(|([i, j], &x)| (i, j, x))
+            .collect();
+        new
+    }
+}
+
+impl<T: Numeric> std::convert::From<Sparse<T>> for Dense<T> {
+    fn from(mat: Sparse<T>) -> Dense<T> {
+        let mut new = Dense::zeros(mat.m, mat.n);
+        for (i, j, x) in mat.data {
+            new[[i, j]] = x;
+        }
+        new
+    }
+}
+
+impl<T: Numeric> Matrix for Sparse<T> {
+    type Element = T;
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn size(&self) -> [usize; 2] {
+        [self.m, self.n]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// returns the stored values without their coordinates
+    fn into_vec(self) -> Vec<T> {
+        self.data.into_iter().map(|(_, _, x)| x).collect()
+    }
+}
+
+impl<T: Numeric> Index<[usize; 2]> for Sparse<T> {
+    type Output = T;
+
+    /// elements which are not stored are zero
+    fn index(&self, idx: [usize; 2]) -> &T {
+        assert!(
+            idx[0] < self.m && idx[1] < self.n,
+            "index [{}, {}] out of range for a {}x{} matrix",
+            idx[0],
+            idx[1],
+            self.m,
+            self.n
+        );
+
+        self.data
+            .iter()
+            .find(|&&(i, j, _)| i == idx[0] && j == idx[1])
+            .map_or(&self.zero, |(_, _, x)| x)
+    }
+}
+
+/// multiplication which only visits the stored nonzero elements
+impl<T: Numeric> Mul<&Dense<T>> for &Sparse<T> {
+    type Output = Result<Dense<T>, MatrixError>;
+
+    fn mul(self, other: &Dense<T>) -> Self::Output {
+        if self.n != other.m {
+            return Err(MatrixError::Incompatibility);
+        }
+
+        let mut out: Dense<T> = Dense::zeros(self.m, other.n);
+        for &(i, k, x) in self.data.iter().filter(|(_, _, x)| *x != T::ZERO) {
+            for j in 0..other.n {
+                out[[i, j]] += x * other[[k, j]];
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_index() {
+        let mut a: Sparse<i32> = Sparse::new(3, 4);
+        assert!(a.is_empty());
+
+        a.insert(0, 1, 5);
+        a.insert(2, 3, -2);
+        a.insert(1, 1, 8);
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.size(), [3, 4]);
+
+        assert_eq!(a[[0, 1]], 5);
+        assert_eq!(a[[2, 3]], -2);
+        assert_eq!(a[[1, 1]], 8);
+        assert_eq!(a[[0, 0]], 0);
+        assert_eq!(a[[2, 2]], 0);
+
+        a.insert(0, 1, 6);
+        assert_eq!(a[[0, 1]], 6);
+        assert_eq!(a.len(), 3);
+
+        a.insert(1, 1, 0);
+        assert_eq!(a[[1, 1]], 0);
+        assert_eq!(a.len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sparse_index_out_of_range() {
+        let a: Sparse<i32> = Sparse::new(3, 4);
+        let _ = a[[3, 0]];
+    }
+
+    #[test]
+    fn sparse_mul() {
+        let mut a: Sparse<i32> = Sparse::new(3, 3);
+        a.insert(0, 0, 2);
+        a.insert(1, 2, -1);
+        a.insert(2, 1, 4);
+
+        let dense_a = mat![
+            2, 0, 0;
+            0, 0, -1;
+            0, 4, 0
+        ];
+        let b = mat![1, 2; 3, 4; 5, 6];
+
+        assert_eq!((&a * &b).unwrap(), (&dense_a * &b).unwrap());
+        assert_eq!((&a * &b).unwrap(), mat![2, 4; -5, -6; 12, 16]);
+
+        let c = mat![1, 2];
+        assert_eq!((&a * &c).unwrap_err(), MatrixError::Incompatibility);
+    }
+
+    #[test]
+    fn sparse_dense_conversion() {
+        let a = mat![
+            0, 3, 0, 0;
+            1, 0, 0, -7;
+            0, 0, 0, 0
+        ];
+
+        let sparse: Sparse<i32> = a.clone().into();
+        assert_eq!(sparse.size(), [3, 4]);
+        assert_eq!(sparse.len(), 3);
+        assert!(sparse.data.iter().all(|&(_, _, x)| x != 0));
+        assert_eq!(sparse[[1, 3]], -7);
+
+        let dense: Dense<i32> = sparse.into();
+        assert_eq!(dense, a);
+    }
+}
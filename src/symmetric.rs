@@ -1,4 +1,4 @@
-use crate::matrix::{Concatenate, Matrix, MatrixError};
+use crate::matrix::{Concatenate, IntoTranspose, Matrix, MatrixError};
 use crate::numerics::Numeric;
 use crate::patterns::TriangularNumberEnumerator;
 use crate::utilities::ApproxEq;
@@ -9,6 +9,7 @@ use std::ops::{Index, IndexMut, Mul};
 /// A struct to represent a symmetrical matrix of nxn
 /// The struct does not have an 'm' value
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Symmetric<T> {
     /// represents the data of the symmetric matrix:
     /// Note that the number of elements is a triangular number such that N = n(n+1)/2
@@ -18,6 +19,44 @@ pub struct Symmetric<T> {
     pub m: usize,
 }
 
+/// Deserialization checks that the data length is the triangular number of the dimensions
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Symmetric<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Symmetric")]
+        struct Raw<T> {
+            data: Vec<T>,
+            n: usize,
+            m: usize,
+        }
+
+        let Raw { data, n, m } = Raw::deserialize(deserializer)?;
+        if m != n {
+            return Err(serde::de::Error::custom(format!(
+                "a symmetric matrix must be square, found {m}x{n}"
+            )));
+        }
+        let len = n
+            .checked_add(1)
+            .and_then(|x| x.checked_mul(n))
+            .map(|x| x / 2)
+            .ok_or_else(|| {
+                serde::de::Error::custom(format!("symmetric matrix dimension {n} overflows"))
+            })?;
+        if data.len() != len {
+            return Err(serde::de::Error::custom(format!(
+                "expected {} elements for a {}x{} symmetric matrix, found {}",
+                len,
+                n,
+                n,
+                data.len()
+            )));
+        }
+        Ok(Symmetric { data, n, m })
+    }
+}
+
 impl<T: Numeric> std::convert::From<Symmetric<T>> for Dense<T> {
     fn from(mat: Symmetric<T>) -> Dense<T> {
         let mut new = Dense::with_capacity(mat.n * mat.n);
@@ -32,6 +71,127 @@ impl<T: Numeric> std::convert::From<Symmetric<T>> for Dense<T> {
     }
 }
 
+/// packs the lower triangle of a square dense matrix after checking that it is symmetric
+impl<T: Numeric> std::convert::TryFrom<Dense<T>> for Symmetric<T> {
+    type Error = MatrixError;
+
+    fn try_from(dense: Dense<T>) -> Result<Self, Self::Error> {
+        if dense.m != dense.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let n = dense.n;
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+        for i in 0..n {
+            for j in 0..=i {
+                if dense[[i, j]] != dense[[j, i]] {
+                    return Err(MatrixError::NotSymmetric);
+                }
+                data.push(dense[[i, j]]);
+            }
+        }
+
+        Ok(Symmetric { data, n, m: n })
+    }
+}
+
+/// the position of [i, j] in the packed lower triangle, mirrored for the upper triangle
+fn triangular_index(i: usize, j: usize) -> usize {
+    if i > j {
+        i * (i + 1) / 2 + j
+    } else {
+        j * (j + 1) / 2 + i
+    }
+}
+
+impl<T: Numeric> Symmetric<T> {
+    /// returns an identity matrix
+    /// Will require a type
+    pub fn eye(n: usize) -> Symmetric<T>
+    where
+        T: std::convert::From<u32>,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(n * (n + 1) / 2);
+        for i in 0..n {
+            for j in 0..=i {
+                data.push(if i == j { 1.into() } else { 0.into() });
+            }
+        }
+        Symmetric { data, n, m: n }
+    }
+
+    /// builds a symmetric matrix by reflecting the lower triangle of a square dense matrix,
+    /// the upper triangle is ignored
+    pub fn from_lower(dense: &Dense<T>) -> Result<Symmetric<T>, MatrixError> {
+        Symmetric::from_triangle(dense, |i, j| dense[[i, j]])
+    }
+
+    /// builds a symmetric matrix by reflecting the upper triangle of a square dense matrix,
+    /// the lower triangle is ignored
+    pub fn from_upper(dense: &Dense<T>) -> Result<Symmetric<T>, MatrixError> {
+        Symmetric::from_triangle(dense, |i, j| dense[[j, i]])
+    }
+
+    /// builds a symmetric matrix from the average of each element and its mirror, useful for
+    /// matrices that have drifted from symmetry through rounding
+    pub fn from_dense_symmetrize(dense: &Dense<T>) -> Result<Symmetric<T>, MatrixError> {
+        Symmetric::from_triangle(dense, |i, j| (dense[[i, j]] + dense[[j, i]]) / T::TWO)
+    }
+
+    /// packs the lower triangle where element (i, j) for j <= i is taken from f
+    fn from_triangle<F: Fn(usize, usize) -> T>(
+        dense: &Dense<T>,
+        f: F,
+    ) -> Result<Symmetric<T>, MatrixError> {
+        if dense.m != dense.n {
+            return Err(MatrixError::NotSquare);
+        }
+
+        let n = dense.n;
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+        for i in 0..n {
+            for j in 0..=i {
+                data.push(f(i, j));
+            }
+        }
+        Ok(Symmetric { data, n, m: n })
+    }
+
+    /// returns the main diagonal, read directly from the packed data at i(i+1)/2 + i
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.n).map(|i| self.data[i * (i + 3) / 2]).collect()
+    }
+
+    /// returns the sum of the main diagonal
+    pub fn trace(&self) -> T {
+        (0..self.n).map(|i| self.data[i * (i + 3) / 2]).sum()
+    }
+
+    /// returns a reference to the element at i, j or None if the index is out of bounds
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i >= self.n || j >= self.n {
+            None
+        } else {
+            self.data.get(triangular_index(i, j))
+        }
+    }
+}
+
+impl<T: Numeric> Dense<T> {
+    /// the gram matrix AᵀA, which is symmetric so only the lower triangle is computed
+    pub fn gram(&self) -> Symmetric<T> {
+        let t = self.t();
+        let n = self.n;
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+        for i in 0..n {
+            for j in 0..=i {
+                data.push((0..self.m).map(|k| t[[i, k]] * self[[k, j]]).sum());
+            }
+        }
+        Symmetric { data, n, m: n }
+    }
+}
+
 impl<T: Numeric> Matrix for Symmetric<T> {
     type Element = T;
 
@@ -56,25 +216,13 @@ impl<T: Numeric> Index<[usize; 2]> for Symmetric<T> {
     type Output = T;
 
     fn index(&self, idx: [usize; 2]) -> &T {
-        let x = if idx[0] > idx[1] {
-            idx[0] * (idx[0] + 1) / 2 + idx[1]
-        } else {
-            idx[1] * (idx[1] + 1) / 2 + idx[0]
-        };
-
-        &self.data[x]
+        &self.data[triangular_index(idx[0], idx[1])]
     }
 }
 
 impl<T: Numeric> IndexMut<[usize; 2]> for Symmetric<T> {
     fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
-        let x = if idx[0] > idx[1] {
-            idx[0] * (idx[0] + 1) / 2 + idx[1]
-        } else {
-            idx[1] * (idx[1] + 1) / 2 + idx[0]
-        };
-
-        &mut self.data[x]
+        &mut self.data[triangular_index(idx[0], idx[1])]
     }
 }
 
@@ -158,14 +306,10 @@ impl ApproxEq<Symmetric<f64>> for Symmetric<f64> {
             return false;
         }
 
-        for i in 0..self.n {
-            for j in 0..i {
-                if (self[[i, j]] - other[[i, j]]).abs() > tolerance {
-                    return false;
-                }
-            }
-        }
-        true
+        self.data
+            .iter()
+            .zip(&other.data)
+            .all(|(a, b)| (a - b).abs() <= tolerance)
     }
 
     fn assert_approx_eq(&self, other: &Symmetric<f64>, tolerance: Self::Check) {
@@ -178,8 +322,9 @@ impl ApproxEq<Symmetric<f64>> for Symmetric<f64> {
             )
         }
 
+        // walks the packed lower triangle including the diagonal
         for i in 0..self.n {
-            for j in 0..i {
+            for j in 0..=i {
                 let delta = (self[[i, j]] - other[[i, j]]).abs();
                 if delta > tolerance {
                     panic!(
@@ -210,7 +355,7 @@ impl ApproxEq<Dense<f64>> for Symmetric<f64> {
         }
 
         for i in 0..self.n {
-            for j in 0..i {
+            for j in 0..self.n {
                 if (self[[i, j]] - other[[i, j]]).abs() > tolerance {
                     return false;
                 }
@@ -232,7 +377,7 @@ impl ApproxEq<Dense<f64>> for Symmetric<f64> {
         }
 
         for i in 0..self.n {
-            for j in 0..i {
+            for j in 0..self.n {
                 let delta = (self[[i, j]] - other[[i, j]]).abs();
                 if delta > tolerance {
                     panic!(
@@ -263,7 +408,7 @@ impl ApproxEq<Symmetric<f64>> for Dense<f64> {
         }
 
         for i in 0..self.n {
-            for j in 0..i {
+            for j in 0..self.n {
                 if (self[[i, j]] - other[[i, j]]).abs() > tolerance {
                     return false;
                 }
@@ -284,7 +429,7 @@ impl ApproxEq<Symmetric<f64>> for Dense<f64> {
         }
 
         for i in 0..self.n {
-            for j in 0..i {
+            for j in 0..self.n {
                 let delta = (self[[i, j]] - other[[i, j]]).abs();
                 if delta > tolerance {
                     panic!(
@@ -305,34 +450,6 @@ impl ApproxEq<Symmetric<f64>> for Dense<f64> {
     }
 }
 
-// impl<T: Numeric> Mul<&Dense<T>> for &Symmetric<T> {
-//     type Output = Result<Dense<T>, MatrixError>;
-//
-//     fn mul(self, rhs: &Dense<T>) -> Self::Output {
-//         if self.n != rhs.m {
-//             Err(MatrixError::Incompatibility)
-//         } else {
-//             let mut out: Dense<T> = Dense::with_capacity(self.n * rhs.n);
-//             out.m = self.n;
-//             out.n = rhs.n;
-//
-//             unsafe {
-//                 out.data.set_len(out.m * out.n);
-//             }
-//
-//             for i in 0..out.m {
-//                 for j in 0..out.n {
-//                     out[[i, j]] = T::ZERO;
-//                     for k in 0..self.n {
-//                         out[[i, j]] += self[[i, k]] * rhs[[k, j]]
-//                     }
-//                 }
-//             }
-//             Ok(out)
-//         }
-//     }
-// }
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +470,249 @@ mod tests {
         assert_eq!(b.data[15], 9);
     }
 
+    #[test]
+    fn test_convert_indexing() {
+        let a = symmat![
+            1.5;
+            -2., 4.;
+            3., 0.25, 8.
+        ];
+        let b: Dense<f64> = a.clone().into();
+        assert_eq!(b.size(), [3, 3]);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(a[[i, j]], b[[i, j]]);
+            }
+        }
+
+        let single: Dense<i32> = symmat![7].into();
+        assert_eq!(single, mat![7]);
+
+        let empty: Dense<i32> = Symmetric {
+            data: vec![],
+            n: 0,
+            m: 0,
+        }
+        .into();
+        assert!(empty.is_empty());
+        assert_eq!(empty.size(), [0, 0]);
+    }
+
+    #[test]
+    fn test_try_from_dense() {
+        let a = mat![
+            13, 26, 29;
+            26, 48, 12;
+            29, 12, 66
+        ];
+        let sym = Symmetric::try_from(a.clone()).unwrap();
+        assert_eq!(sym, symmat![13; 26, 48; 29, 12, 66]);
+
+        let b: Dense<i32> = sym.into();
+        assert_eq!(a, b);
+
+        let asymmetric = mat![1, 2; 3, 4];
+        assert_eq!(
+            Symmetric::try_from(asymmetric).unwrap_err(),
+            MatrixError::NotSymmetric
+        );
+
+        let rectangular = mat![1, 2, 3; 2, 1, 4];
+        assert_eq!(
+            Symmetric::try_from(rectangular).unwrap_err(),
+            MatrixError::NotSquare
+        );
+    }
+
+    #[test]
+    fn test_symmetrize() {
+        let a = mat![4., 1., 2.; 1., 5., 3.; 2., 3., 6.];
+        let sym = Symmetric::from_dense_symmetrize(&a).unwrap();
+        assert_eq!(sym.data, Symmetric::from_lower(&a).unwrap().data);
+
+        let b = mat![1., 2.; 2.5, 3.];
+        Symmetric::from_dense_symmetrize(&b)
+            .unwrap()
+            .assert_approx_eq(&symmat![1.; 2.25, 3.], 0.);
+
+        assert_eq!(
+            Symmetric::from_dense_symmetrize(&mat![1., 2.]).unwrap_err(),
+            MatrixError::NotSquare
+        );
+    }
+
+    #[test]
+    fn symmat_macro() {
+        let a = symmat![1; 2, 3; 4, 5, 6];
+        assert_eq!(a.n, 3);
+        assert_eq!(a.data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a, mat![1, 2, 4; 2, 3, 5; 4, 5, 6]);
+
+        let b = symmat![0.5 => 4];
+        assert_eq!(b.n, 4);
+        assert_eq!(b.len(), 10);
+        assert!(b.data.iter().all(|&x| x == 0.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 of a symmetric matrix must have 2 elements")]
+    fn symmat_macro_row_length() {
+        let _ = symmat![1; 2, 3, 4];
+    }
+
+    #[test]
+    fn test_get() {
+        let a = symmat![
+            1;
+            2, 3;
+            4, 5, 6
+        ];
+
+        assert_eq!(a.get(0, 0), Some(&1));
+        assert_eq!(a.get(1, 2), Some(&5));
+        assert_eq!(a.get(2, 0), Some(&4));
+        assert_eq!(a.get(0, 2), Some(&4));
+        assert_eq!(a.get(2, 2), Some(&6));
+
+        assert_eq!(a.get(3, 0), None);
+        assert_eq!(a.get(0, 3), None);
+        assert_eq!(a.get(3, 3), None);
+    }
+
+    #[test]
+    fn test_eye() {
+        let a: Symmetric<f64> = Symmetric::eye(4);
+        assert_eq!(a.len(), 10);
+        assert_eq!(Dense::from(a), Dense::eye(4));
+
+        let b: Symmetric<f64> = Symmetric::eye(0);
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_from_triangle() {
+        let a = mat![
+            4, 1, 2;
+            1, 5, 3;
+            2, 3, 6
+        ];
+        let lower = Symmetric::from_lower(&a).unwrap();
+        let upper = Symmetric::from_upper(&a).unwrap();
+        assert_eq!(lower, upper);
+        assert_eq!(lower, a);
+
+        // only the chosen triangle is read
+        let b = mat![
+            1, 9, 9;
+            2, 3, 9;
+            4, 5, 6
+        ];
+        assert_eq!(
+            Symmetric::from_lower(&b).unwrap(),
+            symmat![1; 2, 3; 4, 5, 6]
+        );
+        assert_eq!(
+            Symmetric::from_upper(&b).unwrap(),
+            symmat![1; 9, 3; 9, 9, 6]
+        );
+
+        let rectangular = mat![1, 2, 3; 2, 1, 4];
+        assert_eq!(
+            Symmetric::from_lower(&rectangular).unwrap_err(),
+            MatrixError::NotSquare
+        );
+        assert_eq!(
+            Symmetric::from_upper(&rectangular).unwrap_err(),
+            MatrixError::NotSquare
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let a = symmat![
+            2;
+            7, -3;
+            1, 4, 9
+        ];
+        assert_eq!(a.diagonal(), vec![2, -3, 9]);
+        assert_eq!(a.trace(), 8);
+
+        let dense: Dense<i32> = a.into();
+        assert_eq!(dense.trace().unwrap(), 8);
+
+        let empty: Symmetric<i32> = Symmetric {
+            data: vec![],
+            n: 0,
+            m: 0,
+        };
+        assert!(empty.diagonal().is_empty());
+        assert_eq!(empty.trace(), 0);
+    }
+
+    #[test]
+    fn approx_eq() {
+        let a = symmat![1.; 0.5, 2.; -1., 0.25, 3.];
+        let b = symmat![1. + 1e-10; 0.5, 2. - 1e-10; -1., 0.25 + 1e-10, 3.];
+        assert!(a.approx_eq(&b, 1e-8));
+        a.assert_approx_eq(&b, 1e-8);
+
+        // the diagonal is compared
+        let c = symmat![1.; 0.5, 2.1; -1., 0.25, 3.];
+        assert!(!a.approx_eq(&c, 1e-8));
+
+        let d = symmat![1.; 0.5, 2.];
+        assert!(!a.approx_eq(&d, 1e-8));
+
+        let dense: Dense<f64> = c.clone().into();
+        assert!(!a.approx_eq(&dense, 1e-8));
+        assert!(!dense.approx_eq(&a, 1e-8));
+        dense.assert_approx_eq(&c, 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed at element [1, 1]")]
+    fn assert_approx_eq_diagonal() {
+        let a = symmat![1.; 0.5, 2.];
+        let b = symmat![1.; 0.5, 2.5];
+        a.assert_approx_eq(&b, 1e-8);
+    }
+
+    #[test]
+    fn gram() {
+        let a = mat![1, 2, 3; 4, 5, 6; 7, 8, 10; -1, 0, 2];
+        let g = a.gram();
+        assert_eq!(g.n, 3);
+        assert_eq!(g.len(), 6);
+
+        let expected = (a.t() * &a).unwrap();
+        assert_eq!(Dense::from(g), expected);
+
+        let wide = mat![1., 2., 3.];
+        assert_eq!(
+            Dense::from(wide.gram()),
+            mat![1., 2., 3.; 2., 4., 6.; 3., 6., 9.]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let a = symmat![1; 2, 3; 4, 5, 6];
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#"{"data":[1,2,3,4,5,6],"n":3,"m":3}"#);
+
+        let b: Symmetric<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+
+        let invalid = r#"{"data":[1,2,3,4],"n":3,"m":3}"#;
+        assert!(serde_json::from_str::<Symmetric<i32>>(invalid).is_err());
+        let invalid = r#"{"data":[1,2,3],"n":2,"m":3}"#;
+        assert!(serde_json::from_str::<Symmetric<i32>>(invalid).is_err());
+        // n * (n + 1) overflows
+        let invalid = r#"{"data":[],"n":4294967296,"m":4294967296}"#;
+        assert!(serde_json::from_str::<Symmetric<i32>>(invalid).is_err());
+    }
+
     #[test]
     fn test_print() {
         let m = symmat![
@@ -447,6 +807,22 @@ mod tests {
             assert_eq!((a * c).unwrap(), ac)
         }
 
+        #[test]
+        fn symm_symm_mul() {
+            let a = symmat![2; -1, 3; 4, 0, 1];
+            let b = symmat![1; 5, -2; 0, 3, 6];
+            let dense_a: Dense<i32> = a.clone().into();
+            let dense_b: Dense<i32> = b.clone().into();
+
+            let expected = (dense_a * dense_b).unwrap();
+            assert_eq!((&a * &b).unwrap(), expected);
+            assert_eq!((a * b).unwrap(), expected);
+
+            let c = symmat![1; 2, 3];
+            let d = symmat![1; 2, 3; 4, 5, 6];
+            assert_eq!((&c * &d).unwrap_err(), MatrixError::Incompatibility);
+        }
+
         #[test]
         fn matrix_mul_symmetry() {
             let a = symmat![